@@ -87,6 +87,9 @@ pub struct FcFontMetadataC {
     trademark: *mut c_char,
     unique_id: *mut c_char,
     version: *mut c_char,
+    /// Mirrors `FcFontMetadata::metrics`; `FontMetrics` is `#[repr(C)]` and all-`Copy`, so it's
+    /// embedded directly rather than split into individual fields.
+    metrics: FontMetrics,
 }
 
 /// C-compatible pattern for matching
@@ -94,15 +97,37 @@ pub struct FcFontMetadataC {
 pub struct FcPatternC {
     name: *mut c_char,
     family: *mut c_char,
+    fullname: *mut c_char,
     italic: PatternMatch,
     oblique: PatternMatch,
     bold: PatternMatch,
     monospace: PatternMatch,
+    spacing: FcSpacing,
+    serif: PatternMatch,
     condensed: PatternMatch,
     weight: FcWeight,
     stretch: FcStretch,
+    exact_style: bool,
+    scalable: PatternMatch,
+    outline: PatternMatch,
+    embedded_bitmap: PatternMatch,
     unicode_ranges: *mut UnicodeRange,
     unicode_ranges_count: usize,
+    cmap_coverage: *mut UnicodeRange,
+    cmap_coverage_count: usize,
+    script_coverage: *mut [u8; 4],
+    script_coverage_count: usize,
+    required_scripts: *mut [u8; 4],
+    required_scripts_count: usize,
+    foundry: *mut c_char,
+    has_weight_value: bool,
+    weight_value: u16,
+    has_weight_axis: bool,
+    weight_axis_min: u16,
+    weight_axis_default: u16,
+    weight_axis_max: u16,
+    languages: *mut *mut c_char,
+    languages_count: usize,
     metadata: FcFontMetadataC,
 }
 
@@ -112,10 +137,17 @@ pub enum FcReasonTypeC {
     NameMismatch = 0,
     FamilyMismatch = 1,
     StyleMismatch = 2,
-    WeightMismatch = 3,
+    WeightSubstituted = 3,
     StretchMismatch = 4,
     UnicodeRangeMismatch = 5,
     Success = 6,
+    SpacingMismatch = 7,
+    LanguageMismatch = 8,
+    ScriptMismatch = 9,
+    Substituted = 10,
+    FullNameResolved = 11,
+    ExactStyleMismatch = 12,
+    StyleScored = 13,
 }
 
 /// Trace message level
@@ -174,10 +206,70 @@ unsafe fn c_char_to_option_string(s: *const c_char) -> Option<String> {
     }
 }
 
+/// Helper to convert a `Vec<String>` (e.g. `FcPattern::languages`) to a C array of C strings
+fn strings_to_c(strings: &[String]) -> (*mut *mut c_char, usize) {
+    let count = strings.len();
+    if count == 0 {
+        return (ptr::null_mut(), 0);
+    }
+    let mut ptrs: Vec<*mut c_char> = strings
+        .iter()
+        .map(|s| CString::new(s.as_str()).unwrap_or_default().into_raw())
+        .collect();
+    let ptr = ptrs.as_mut_ptr();
+    mem::forget(ptrs);
+    (ptr, count)
+}
+
+/// Helper to convert a C array of C strings back to `Vec<String>`
+unsafe fn c_strings_to_vec(ptr: *const *mut c_char, count: usize) -> Vec<String> {
+    if ptr.is_null() || count == 0 {
+        return Vec::new();
+    }
+    slice::from_raw_parts(ptr, count)
+        .iter()
+        .map(|&s| c_char_to_option_string(s).unwrap_or_default())
+        .collect()
+}
+
+/// Helper to free a C array of C strings built by `strings_to_c`
+unsafe fn free_c_strings(ptr: *mut *mut c_char, count: usize) {
+    if ptr.is_null() || count == 0 {
+        return;
+    }
+    let ptrs = Vec::from_raw_parts(ptr, count, count);
+    for p in ptrs {
+        free_c_string(p);
+    }
+}
+
+/// Helper to convert a `Vec<[u8; 4]>` (e.g. `script_coverage`/`required_scripts`) to a C array
+fn tags_to_c(tags: &[[u8; 4]]) -> (*mut [u8; 4], usize) {
+    let count = tags.len();
+    if count == 0 {
+        return (ptr::null_mut(), 0);
+    }
+    let mut v = tags.to_vec();
+    let ptr = v.as_mut_ptr();
+    mem::forget(v);
+    (ptr, count)
+}
+
+/// Helper to convert a C array of OpenType script tags back to `Vec<[u8; 4]>`
+unsafe fn c_tags_to_vec(ptr: *const [u8; 4], count: usize) -> Vec<[u8; 4]> {
+    if ptr.is_null() || count == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(ptr, count).to_vec()
+    }
+}
+
 /// Convert Rust FcPattern to C FcPatternC
 fn pattern_to_c(pattern: &FcPattern) -> FcPatternC {
     let name = option_string_to_c_char(pattern.name.as_ref());
     let family = option_string_to_c_char(pattern.family.as_ref());
+    let fullname = option_string_to_c_char(pattern.fullname.as_ref());
+    let foundry = option_string_to_c_char(pattern.foundry.as_ref());
 
     let unicode_ranges_count = pattern.unicode_ranges.len();
     let unicode_ranges = if unicode_ranges_count > 0 {
@@ -192,6 +284,33 @@ fn pattern_to_c(pattern: &FcPattern) -> FcPatternC {
         ptr::null_mut()
     };
 
+    let cmap_coverage_count = pattern.cmap_coverage.len();
+    let cmap_coverage = if cmap_coverage_count > 0 {
+        let mut ranges = Vec::with_capacity(cmap_coverage_count);
+        for range in &pattern.cmap_coverage {
+            ranges.push(*range);
+        }
+        let ptr = ranges.as_mut_ptr();
+        mem::forget(ranges);
+        ptr
+    } else {
+        ptr::null_mut()
+    };
+
+    let (script_coverage, script_coverage_count) = tags_to_c(&pattern.script_coverage);
+    let (required_scripts, required_scripts_count) = tags_to_c(&pattern.required_scripts);
+    let (languages, languages_count) = strings_to_c(&pattern.languages);
+
+    let (has_weight_value, weight_value) = match pattern.weight_value {
+        Some(v) => (true, v),
+        None => (false, 0),
+    };
+    let (has_weight_axis, weight_axis_min, weight_axis_default, weight_axis_max) =
+        match pattern.weight_axis {
+            Some((min, default, max)) => (true, min, default, max),
+            None => (false, 0, 0, 0),
+        };
+
     let metadata = FcFontMetadataC {
         copyright: option_string_to_c_char(pattern.metadata.copyright.as_ref()),
         designer: option_string_to_c_char(pattern.metadata.designer.as_ref()),
@@ -210,20 +329,43 @@ fn pattern_to_c(pattern: &FcPattern) -> FcPatternC {
         trademark: option_string_to_c_char(pattern.metadata.trademark.as_ref()),
         unique_id: option_string_to_c_char(pattern.metadata.unique_id.as_ref()),
         version: option_string_to_c_char(pattern.metadata.version.as_ref()),
+        metrics: pattern.metadata.metrics,
     };
 
     FcPatternC {
         name,
         family,
+        fullname,
         italic: pattern.italic,
         oblique: pattern.oblique,
         bold: pattern.bold,
         monospace: pattern.monospace,
+        spacing: pattern.spacing,
+        serif: pattern.serif,
         condensed: pattern.condensed,
         weight: pattern.weight,
         stretch: pattern.stretch,
+        exact_style: pattern.exact_style,
+        scalable: pattern.scalable,
+        outline: pattern.outline,
+        embedded_bitmap: pattern.embedded_bitmap,
         unicode_ranges,
         unicode_ranges_count,
+        cmap_coverage,
+        cmap_coverage_count,
+        script_coverage,
+        script_coverage_count,
+        required_scripts,
+        required_scripts_count,
+        foundry,
+        has_weight_value,
+        weight_value,
+        has_weight_axis,
+        weight_axis_min,
+        weight_axis_default,
+        weight_axis_max,
+        languages,
+        languages_count,
         metadata,
     }
 }
@@ -234,6 +376,8 @@ unsafe fn c_to_pattern(pattern: *const FcPatternC) -> FcPattern {
 
     let name = c_char_to_option_string(pattern.name);
     let family = c_char_to_option_string(pattern.family);
+    let fullname = c_char_to_option_string(pattern.fullname);
+    let foundry = c_char_to_option_string(pattern.foundry);
 
     let mut unicode_ranges = Vec::new();
     if !pattern.unicode_ranges.is_null() && pattern.unicode_ranges_count > 0 {
@@ -241,6 +385,24 @@ unsafe fn c_to_pattern(pattern: *const FcPatternC) -> FcPattern {
             slice::from_raw_parts(pattern.unicode_ranges, pattern.unicode_ranges_count).to_vec();
     }
 
+    let mut cmap_coverage = Vec::new();
+    if !pattern.cmap_coverage.is_null() && pattern.cmap_coverage_count > 0 {
+        cmap_coverage =
+            slice::from_raw_parts(pattern.cmap_coverage, pattern.cmap_coverage_count).to_vec();
+    }
+
+    let script_coverage = c_tags_to_vec(pattern.script_coverage, pattern.script_coverage_count);
+    let required_scripts =
+        c_tags_to_vec(pattern.required_scripts, pattern.required_scripts_count);
+    let languages = c_strings_to_vec(pattern.languages, pattern.languages_count);
+
+    let weight_value = pattern.has_weight_value.then_some(pattern.weight_value);
+    let weight_axis = pattern.has_weight_axis.then_some((
+        pattern.weight_axis_min,
+        pattern.weight_axis_default,
+        pattern.weight_axis_max,
+    ));
+
     let metadata = FcFontMetadata {
         copyright: c_char_to_option_string(pattern.metadata.copyright),
         designer: c_char_to_option_string(pattern.metadata.designer),
@@ -259,20 +421,36 @@ unsafe fn c_to_pattern(pattern: *const FcPatternC) -> FcPattern {
         trademark: c_char_to_option_string(pattern.metadata.trademark),
         unique_id: c_char_to_option_string(pattern.metadata.unique_id),
         version: c_char_to_option_string(pattern.metadata.version),
+        metrics: pattern.metadata.metrics,
     };
 
     FcPattern {
         name,
         family,
+        fullname,
         italic: pattern.italic,
         oblique: pattern.oblique,
         bold: pattern.bold,
         monospace: pattern.monospace,
+        spacing: pattern.spacing,
+        serif: pattern.serif,
         condensed: pattern.condensed,
         weight: pattern.weight,
+        weight_value,
+        weight_axis,
         stretch: pattern.stretch,
+        exact_style: pattern.exact_style,
         unicode_ranges,
+        cmap_coverage,
+        script_coverage,
         metadata,
+        foundry,
+        languages,
+        required_scripts,
+        scalable: pattern.scalable,
+        outline: pattern.outline,
+        embedded_bitmap: pattern.embedded_bitmap,
+        unknown_properties: Vec::new(),
     }
 }
 
@@ -286,6 +464,8 @@ unsafe fn free_pattern_c(pattern: *mut FcPatternC) {
 
     free_c_string(pattern.name);
     free_c_string(pattern.family);
+    free_c_string(pattern.fullname);
+    free_c_string(pattern.foundry);
 
     if !pattern.unicode_ranges.is_null() && pattern.unicode_ranges_count > 0 {
         let _ = Vec::from_raw_parts(
@@ -295,6 +475,32 @@ unsafe fn free_pattern_c(pattern: *mut FcPatternC) {
         );
     }
 
+    if !pattern.cmap_coverage.is_null() && pattern.cmap_coverage_count > 0 {
+        let _ = Vec::from_raw_parts(
+            pattern.cmap_coverage,
+            pattern.cmap_coverage_count,
+            pattern.cmap_coverage_count,
+        );
+    }
+
+    if !pattern.script_coverage.is_null() && pattern.script_coverage_count > 0 {
+        let _ = Vec::from_raw_parts(
+            pattern.script_coverage,
+            pattern.script_coverage_count,
+            pattern.script_coverage_count,
+        );
+    }
+
+    if !pattern.required_scripts.is_null() && pattern.required_scripts_count > 0 {
+        let _ = Vec::from_raw_parts(
+            pattern.required_scripts,
+            pattern.required_scripts_count,
+            pattern.required_scripts_count,
+        );
+    }
+
+    free_c_strings(pattern.languages, pattern.languages_count);
+
     // Free metadata strings
     free_c_string(pattern.metadata.copyright);
     free_c_string(pattern.metadata.designer);
@@ -485,6 +691,49 @@ pub extern "C" fn fc_pattern_free(pattern: *mut FcPatternC) {
     }
 }
 
+/// Parse a fontconfig `fc-match`-style pattern string (e.g.
+/// `"DejaVu Sans Mono:style=Bold:weight=200:slant=italic"` or `"monospace:pixelsize=11"`, see
+/// `FcPattern::parse`) into a pattern. Unrecognized keys are dropped rather than rejected
+/// (`FcPatternC` has no field to carry them), so this
+/// only returns null if `spec` itself is null or not valid UTF-8, or if a recognized key (e.g.
+/// `weight=`) has a malformed value.
+#[no_mangle]
+pub extern "C" fn fc_pattern_from_string(spec: *const c_char) -> *mut FcPatternC {
+    if spec.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let spec_rust = match CStr::from_ptr(spec).to_str() {
+            Ok(s) => s,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        match FcPattern::parse(spec_rust) {
+            Ok(pattern) => Box::into_raw(Box::new(pattern_to_c(&pattern))),
+            Err(_) => ptr::null_mut(),
+        }
+    }
+}
+
+/// Render a pattern back into fontconfig's `family:key=value:...` string syntax (the inverse
+/// of `fc_pattern_from_string`, see `FcPattern::to_fc_string`). Returns null if `pattern` is
+/// null. The caller owns the returned string, which is allocated by Rust via
+/// `CString::into_raw`; no function exported from this module releases it.
+#[no_mangle]
+pub extern "C" fn fc_pattern_to_string(pattern: *const FcPatternC) -> *mut c_char {
+    if pattern.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let pattern_rust = c_to_pattern(pattern);
+        CString::new(pattern_rust.to_fc_string())
+            .unwrap_or_default()
+            .into_raw()
+    }
+}
+
 /// Set pattern name
 #[no_mangle]
 pub extern "C" fn fc_pattern_set_name(pattern: *mut FcPatternC, name: *const c_char) {
@@ -562,6 +811,48 @@ pub extern "C" fn fc_pattern_set_monospace(pattern: *mut FcPatternC, monospace:
     }
 }
 
+/// Set whether the pattern requires (or rejects) a scalable, vector-outline face
+#[no_mangle]
+pub extern "C" fn fc_pattern_set_scalable(pattern: *mut FcPatternC, scalable: PatternMatch) {
+    if pattern.is_null() {
+        return;
+    }
+
+    unsafe {
+        let pattern = &mut *pattern;
+        pattern.scalable = scalable;
+    }
+}
+
+/// Set whether the pattern requires (or rejects) an outline-rendered face
+#[no_mangle]
+pub extern "C" fn fc_pattern_set_outline(pattern: *mut FcPatternC, outline: PatternMatch) {
+    if pattern.is_null() {
+        return;
+    }
+
+    unsafe {
+        let pattern = &mut *pattern;
+        pattern.outline = outline;
+    }
+}
+
+/// Set whether the pattern requires (or rejects) embedded bitmap strikes
+#[no_mangle]
+pub extern "C" fn fc_pattern_set_embedded_bitmap(
+    pattern: *mut FcPatternC,
+    embedded_bitmap: PatternMatch,
+) {
+    if pattern.is_null() {
+        return;
+    }
+
+    unsafe {
+        let pattern = &mut *pattern;
+        pattern.embedded_bitmap = embedded_bitmap;
+    }
+}
+
 /// Set pattern weight
 #[no_mangle]
 pub extern "C" fn fc_pattern_set_weight(pattern: *mut FcPatternC, weight: FcWeight) {
@@ -717,9 +1008,16 @@ pub extern "C" fn fc_trace_get_reason_type(trace: *const FcTraceMsgC) -> FcReaso
             MatchReason::NameMismatch { .. } => FcReasonTypeC::NameMismatch,
             MatchReason::FamilyMismatch { .. } => FcReasonTypeC::FamilyMismatch,
             MatchReason::StyleMismatch { .. } => FcReasonTypeC::StyleMismatch,
-            MatchReason::WeightMismatch { .. } => FcReasonTypeC::WeightMismatch,
+            MatchReason::WeightSubstituted { .. } => FcReasonTypeC::WeightSubstituted,
             MatchReason::StretchMismatch { .. } => FcReasonTypeC::StretchMismatch,
             MatchReason::UnicodeRangeMismatch { .. } => FcReasonTypeC::UnicodeRangeMismatch,
+            MatchReason::SpacingMismatch { .. } => FcReasonTypeC::SpacingMismatch,
+            MatchReason::LanguageMismatch { .. } => FcReasonTypeC::LanguageMismatch,
+            MatchReason::ScriptMismatch { .. } => FcReasonTypeC::ScriptMismatch,
+            MatchReason::Substituted { .. } => FcReasonTypeC::Substituted,
+            MatchReason::FullNameResolved { .. } => FcReasonTypeC::FullNameResolved,
+            MatchReason::ExactStyleMismatch { .. } => FcReasonTypeC::ExactStyleMismatch,
+            MatchReason::StyleScored { .. } => FcReasonTypeC::StyleScored,
             MatchReason::Success => FcReasonTypeC::Success,
         }
     }
@@ -961,12 +1259,42 @@ pub extern "C" fn fc_cache_get_font_metadata(
             trademark: option_string_to_c_char(pattern.metadata.trademark.as_ref()),
             unique_id: option_string_to_c_char(pattern.metadata.unique_id.as_ref()),
             version: option_string_to_c_char(pattern.metadata.version.as_ref()),
+            metrics: pattern.metadata.metrics,
         });
 
         Box::into_raw(metadata)
     }
 }
 
+/// Check whether the font identified by `id` covers `codepoint`, using its precomputed
+/// `cmap_coverage`/`unicode_ranges` (see `FcPattern::contains_char`) rather than reopening and
+/// re-parsing the face's `cmap` table. Returns `false` if `cache`/`id` is null, `id` is
+/// unknown, or `codepoint` is not a valid Unicode scalar value.
+#[no_mangle]
+pub extern "C" fn fc_cache_font_covers_char(
+    cache: *const FcFontCache,
+    id: *const FcFontIdC,
+    codepoint: u32,
+) -> bool {
+    if cache.is_null() || id.is_null() {
+        return false;
+    }
+
+    let c = match char::from_u32(codepoint) {
+        Some(c) => c,
+        None => return false,
+    };
+
+    unsafe {
+        let cache = &*cache;
+        let id_rust = FontId::from_fontid_c(&*id);
+
+        cache
+            .get_metadata_by_id(&id_rust)
+            .is_some_and(|pattern| pattern.contains_char(c))
+    }
+}
+
 /// Create a new in-memory font
 #[no_mangle]
 pub extern "C" fn fc_font_new(
@@ -1036,6 +1364,357 @@ pub extern "C" fn fc_cache_list_fonts(
     }
 }
 
+/// `fc_list` object-set bitflags selecting which `FcFontInfoExtC` fields get materialized.
+/// Unselected string fields are left null, an unselected weight reads `FcWeight::Normal` and
+/// an unselected slant reads `PatternMatch::DontCare`, so large enumerations don't pay for
+/// thousands of wasted `CString` allocations.
+pub const FC_OBJECT_FAMILY: u32 = 1 << 0;
+pub const FC_OBJECT_STYLE: u32 = 1 << 1;
+pub const FC_OBJECT_WEIGHT: u32 = 1 << 2;
+pub const FC_OBJECT_SLANT: u32 = 1 << 3;
+pub const FC_OBJECT_FILE: u32 = 1 << 4;
+pub const FC_OBJECT_INDEX: u32 = 1 << 5;
+pub const FC_OBJECT_FULL_NAME: u32 = 1 << 6;
+pub const FC_OBJECT_POSTSCRIPT_NAME: u32 = 1 << 7;
+
+/// Extended font info for `fc_list`, with fields selectively filled in per `object_set`
+#[repr(C)]
+pub struct FcFontInfoExtC {
+    id: FcFontIdC,
+    family: *mut c_char,
+    style: *mut c_char,
+    weight: FcWeight,
+    slant: PatternMatch,
+    file: *mut c_char,
+    index: usize,
+    full_name: *mut c_char,
+    postscript_name: *mut c_char,
+}
+
+/// Enumerate the cache, optionally filtered by `pattern`, materializing only the fields
+/// selected by `object_set` (an OR of the `FC_OBJECT_*` flags). Pass a null `pattern` to
+/// list every font in the cache.
+#[no_mangle]
+pub extern "C" fn fc_list(
+    cache: *const FcFontCache,
+    pattern: *const FcPatternC,
+    object_set: u32,
+    count: *mut usize,
+) -> *mut FcFontInfoExtC {
+    if cache.is_null() || count.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+
+        let matching_ids: Option<std::collections::BTreeSet<FontId>> = if pattern.is_null() {
+            None
+        } else {
+            let pattern_rust = c_to_pattern(pattern);
+            let mut trace = Vec::new();
+            Some(
+                cache
+                    .query_all(&pattern_rust, &mut trace)
+                    .into_iter()
+                    .map(|m| m.id)
+                    .collect(),
+            )
+        };
+
+        let mut results = Vec::new();
+        for (stored_pattern, id) in cache.list() {
+            if let Some(ids) = &matching_ids {
+                if !ids.contains(&id) {
+                    continue;
+                }
+            }
+
+            let family = if object_set & FC_OBJECT_FAMILY != 0 {
+                option_string_to_c_char(stored_pattern.family.as_ref())
+            } else {
+                ptr::null_mut()
+            };
+
+            let style = if object_set & FC_OBJECT_STYLE != 0 {
+                option_string_to_c_char(stored_pattern.metadata.font_subfamily.as_ref())
+            } else {
+                ptr::null_mut()
+            };
+
+            let weight = if object_set & FC_OBJECT_WEIGHT != 0 {
+                stored_pattern.weight
+            } else {
+                FcWeight::Normal
+            };
+
+            let slant = if object_set & FC_OBJECT_SLANT != 0 {
+                stored_pattern.italic
+            } else {
+                PatternMatch::DontCare
+            };
+
+            let (file, index) = if object_set & (FC_OBJECT_FILE | FC_OBJECT_INDEX) != 0 {
+                match cache.get_font_by_id(&id) {
+                    Some(FontSource::Disk(path)) => (
+                        if object_set & FC_OBJECT_FILE != 0 {
+                            CString::new(path.path.clone()).unwrap_or_default().into_raw()
+                        } else {
+                            ptr::null_mut()
+                        },
+                        path.font_index,
+                    ),
+                    Some(FontSource::Memory(font)) => (
+                        if object_set & FC_OBJECT_FILE != 0 {
+                            CString::new(format!("memory:{}", font.id))
+                                .unwrap_or_default()
+                                .into_raw()
+                        } else {
+                            ptr::null_mut()
+                        },
+                        font.font_index,
+                    ),
+                    None => (ptr::null_mut(), 0),
+                }
+            } else {
+                (ptr::null_mut(), 0)
+            };
+
+            let full_name = if object_set & FC_OBJECT_FULL_NAME != 0 {
+                option_string_to_c_char(stored_pattern.metadata.full_name.as_ref())
+            } else {
+                ptr::null_mut()
+            };
+
+            let postscript_name = if object_set & FC_OBJECT_POSTSCRIPT_NAME != 0 {
+                option_string_to_c_char(stored_pattern.metadata.postscript_name.as_ref())
+            } else {
+                ptr::null_mut()
+            };
+
+            results.push(FcFontInfoExtC {
+                id: FcFontIdC::from_fontid(&id),
+                family,
+                style,
+                weight,
+                slant,
+                file,
+                index,
+                full_name,
+                postscript_name,
+            });
+        }
+
+        *count = results.len();
+        if results.is_empty() {
+            return ptr::null_mut();
+        }
+
+        let ptr = results.as_mut_ptr();
+        mem::forget(results);
+
+        ptr
+    }
+}
+
+/// Free an array of `FcFontInfoExtC` returned by `fc_list`
+#[no_mangle]
+pub extern "C" fn fc_list_free(info: *mut FcFontInfoExtC, count: usize) {
+    if info.is_null() || count == 0 {
+        return;
+    }
+
+    unsafe {
+        let info_slice = slice::from_raw_parts_mut(info, count);
+
+        for item in info_slice {
+            free_c_string(item.family);
+            free_c_string(item.style);
+            free_c_string(item.file);
+            free_c_string(item.full_name);
+            free_c_string(item.postscript_name);
+        }
+
+        let _ = Vec::from_raw_parts(info, count, count);
+    }
+}
+
+/// Four coordinated faces (regular/bold/italic/bold-italic) returned by `fc_match_faces`.
+/// A null slot means no face satisfied that style combination at all.
+#[repr(C)]
+pub struct FcFaceSetC {
+    regular: *mut FcFontMatchC,
+    bold: *mut FcFontMatchC,
+    italic: *mut FcFontMatchC,
+    bold_italic: *mut FcFontMatchC,
+}
+
+/// Resolve one style slot for `fc_match_faces`: query `base` with `bold`/`italic` toggled,
+/// first constrained to `preferred_family` (so e.g. the bold face comes from the same
+/// family that won the regular match) and, if that yields nothing, again without the
+/// family constraint so the style combination still resolves to the overall best match.
+fn match_face_slot(
+    cache: &FcFontCache,
+    base: &FcPattern,
+    preferred_family: Option<&str>,
+    bold: PatternMatch,
+    italic: PatternMatch,
+) -> Option<FontMatch> {
+    let mut trace = Vec::new();
+
+    if let Some(family) = preferred_family {
+        let mut attempt = base.clone();
+        attempt.family = Some(family.to_string());
+        attempt.bold = bold;
+        attempt.italic = italic;
+        if let Some(m) = cache.query(&attempt, &mut trace) {
+            return Some(m);
+        }
+    }
+
+    let mut attempt = base.clone();
+    attempt.bold = bold;
+    attempt.italic = italic;
+    cache.query(&attempt, &mut trace)
+}
+
+/// Resolve coordinated regular/bold/italic/bold-italic faces for one family in a single
+/// call: the regular match is found first via ordinary `query`, then the other three
+/// slots are resolved preferring that same family, falling back to the best style match
+/// overall if the family has no face in that style. This guarantees the bold face is the
+/// bold of the family that won the regular match rather than an unrelated font that merely
+/// scored well on the bold query. Returns null if `cache` or `base_pattern` is null.
+#[no_mangle]
+pub extern "C" fn fc_match_faces(
+    cache: *const FcFontCache,
+    base_pattern: *const FcPatternC,
+) -> *mut FcFaceSetC {
+    if cache.is_null() || base_pattern.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let base = c_to_pattern(base_pattern);
+
+        let mut trace = Vec::new();
+        let regular = cache.query(&base, &mut trace);
+
+        let preferred_family = regular
+            .as_ref()
+            .and_then(|m| cache.get_metadata_by_id(&m.id))
+            .and_then(|p| p.family.as_deref())
+            .or(base.family.as_deref());
+
+        let bold = match_face_slot(
+            cache,
+            &base,
+            preferred_family,
+            PatternMatch::True,
+            PatternMatch::False,
+        );
+        let italic = match_face_slot(
+            cache,
+            &base,
+            preferred_family,
+            PatternMatch::False,
+            PatternMatch::True,
+        );
+        let bold_italic = match_face_slot(
+            cache,
+            &base,
+            preferred_family,
+            PatternMatch::True,
+            PatternMatch::True,
+        );
+
+        let to_c_ptr = |m: &Option<FontMatch>| -> *mut FcFontMatchC {
+            match m {
+                Some(m) => Box::into_raw(Box::new(font_match_to_c(m))),
+                None => ptr::null_mut(),
+            }
+        };
+
+        let face_set = FcFaceSetC {
+            regular: to_c_ptr(&regular),
+            bold: to_c_ptr(&bold),
+            italic: to_c_ptr(&italic),
+            bold_italic: to_c_ptr(&bold_italic),
+        };
+
+        Box::into_raw(Box::new(face_set))
+    }
+}
+
+/// Free a face set returned by `fc_match_faces`
+#[no_mangle]
+pub extern "C" fn fc_face_set_free(face_set: *mut FcFaceSetC) {
+    if face_set.is_null() {
+        return;
+    }
+
+    unsafe {
+        let face_set = &mut *face_set;
+        free_font_match_c(face_set.regular);
+        free_font_match_c(face_set.bold);
+        free_font_match_c(face_set.italic);
+        free_font_match_c(face_set.bold_italic);
+        let _ = Box::from_raw(face_set);
+    }
+}
+
+/// Get the raw bytes of a font by ID. On non-wasm `std` builds this is backed by
+/// `FcFontCache::get_font_bytes_mmap`, so disk fonts are memory-mapped and cached rather than
+/// re-read on every call. Writes the byte length to `out_len` and returns a pointer to the
+/// bytes, or null (with `*out_len = 0`) if `cache`/`id`/`out_len` is null or `id` is unknown.
+///
+/// The returned pointer is borrowed from `cache` - it stays valid for as long as `cache` does
+/// and must NOT be passed to `free()`. Release it via `fc_cache_get_font_bytes_free` instead,
+/// which exists only for API symmetry with the rest of this header.
+#[no_mangle]
+pub extern "C" fn fc_cache_get_font_bytes(
+    cache: *const FcFontCache,
+    id: *const FcFontIdC,
+    out_len: *mut usize,
+) -> *const u8 {
+    if out_len.is_null() {
+        return ptr::null();
+    }
+    if cache.is_null() || id.is_null() {
+        unsafe { *out_len = 0 };
+        return ptr::null();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let id_rust = FontId::from_fontid_c(&*id);
+
+        #[cfg(all(not(target_family = "wasm"), feature = "std"))]
+        let bytes = cache.get_font_bytes_mmap(&id_rust);
+
+        #[cfg(not(all(not(target_family = "wasm"), feature = "std")))]
+        let bytes: Option<&[u8]> = None;
+
+        match bytes {
+            Some(bytes) => {
+                *out_len = bytes.len();
+                bytes.as_ptr()
+            }
+            None => {
+                *out_len = 0;
+                ptr::null()
+            }
+        }
+    }
+}
+
+/// Placeholder release function for `fc_cache_get_font_bytes`. The bytes it returns are owned
+/// by `cache` (either its mmap cache or `memory_fonts`), so there is nothing to free here
+/// independently of the cache itself - this only exists so callers have a `_free` counterpart
+/// to call, matching every other accessor in this header.
+#[no_mangle]
+pub extern "C" fn fc_cache_get_font_bytes_free(_bytes: *const u8, _len: usize) {}
+
 /// Add in-memory fonts to the cache
 #[no_mangle]
 pub extern "C" fn fc_cache_add_memory_fonts(
@@ -1182,3 +1861,417 @@ pub extern "C" fn fc_cache_query_for_text(
         ptr
     }
 }
+
+/// One run of `text` (as a byte range) resolved to a single font by `fc_cache_itemize_text`.
+#[repr(C)]
+pub struct FcTextRunC {
+    byte_start: usize,
+    byte_len: usize,
+    font: FcFontIdC,
+}
+
+/// Split `text` into runs with a single resolved font per run (`FcFontCache::itemize_text`),
+/// merging consecutive characters covered by the same best font into one run instead of
+/// returning a match per character. Writes the run count to `*out_count` and returns an array
+/// owned by the caller - free it with `fc_text_runs_free`. Returns null (with `*out_count = 0`)
+/// if any argument is null, `text` is empty, or nothing in the cache covers it.
+#[no_mangle]
+pub extern "C" fn fc_cache_itemize_text(
+    cache: *const FcFontCache,
+    pattern: *const FcPatternC,
+    text: *const c_char,
+    out_count: *mut usize,
+) -> *mut FcTextRunC {
+    if out_count.is_null() {
+        return ptr::null_mut();
+    }
+    if cache.is_null() || pattern.is_null() || text.is_null() {
+        unsafe { *out_count = 0 };
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let pattern_rust = c_to_pattern(pattern);
+        let text_rust = CStr::from_ptr(text).to_string_lossy().into_owned();
+
+        let mut trace_msgs = Vec::new();
+        let runs = cache.itemize_text(&pattern_rust, &text_rust, &mut trace_msgs);
+
+        if runs.is_empty() {
+            *out_count = 0;
+            return ptr::null_mut();
+        }
+
+        let mut runs_c: Vec<FcTextRunC> = runs
+            .iter()
+            .map(|run| FcTextRunC {
+                byte_start: run.range.start,
+                byte_len: run.range.end - run.range.start,
+                font: FcFontIdC::from_fontid(&run.font),
+            })
+            .collect();
+
+        *out_count = runs_c.len();
+        let ptr = runs_c.as_mut_ptr();
+        mem::forget(runs_c);
+
+        ptr
+    }
+}
+
+/// Free an array returned by `fc_cache_itemize_text`.
+#[no_mangle]
+pub extern "C" fn fc_text_runs_free(runs: *mut FcTextRunC, count: usize) {
+    if runs.is_null() {
+        return;
+    }
+
+    unsafe {
+        let _ = Vec::from_raw_parts(runs, count, count);
+    }
+}
+
+/// Opaque handle for a cached, base-pattern-ranked fallback list, as produced by
+/// `fc_font_sort`. Unlike `fc_cache_query_all`/`fc_cache_query_for_text`, which re-rank on
+/// every call, this holds the sort order computed once so repeated `fc_font_sort_lookup`
+/// calls for different codepoints are just a linear scan instead of a full re-match.
+pub struct FcFontSortResult {
+    ranked: Vec<(FontId, Vec<UnicodeRange>)>,
+}
+
+/// Compute a cached, ranked fallback list for `pattern`'s base style (weight, stretch,
+/// italic, language, etc. - independent of any particular codepoint), using the same
+/// order as `FcFontCache::build_fallback_order`. Pass the result to `fc_font_sort_lookup`
+/// to find the best font for each codepoint without re-ranking, and to `fc_font_sort_free`
+/// once done. Returns null if `cache` or `pattern` is null.
+#[no_mangle]
+pub extern "C" fn fc_font_sort(
+    cache: *const FcFontCache,
+    pattern: *const FcPatternC,
+) -> *mut FcFontSortResult {
+    if cache.is_null() || pattern.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let pattern_rust = c_to_pattern(pattern);
+
+        let ranked = cache
+            .build_fallback_order(&pattern_rust)
+            .into_iter()
+            .filter_map(|id| {
+                cache
+                    .get_metadata_by_id(&id)
+                    .map(|meta| (id, meta.unicode_ranges.clone()))
+            })
+            .collect();
+
+        Box::into_raw(Box::new(FcFontSortResult { ranked }))
+    }
+}
+
+/// Scan `result` in its already-ranked order for the first font whose Unicode coverage
+/// includes `codepoint`, writing whether one was found into `*found`. Returns a
+/// zeroed `FcFontIdC` and sets `*found` to `false` if `result` is null, `codepoint` is not a
+/// valid Unicode scalar value, or nothing in the ranked list covers it.
+#[no_mangle]
+pub extern "C" fn fc_font_sort_lookup(
+    result: *const FcFontSortResult,
+    codepoint: u32,
+    found: *mut bool,
+) -> FcFontIdC {
+    let zero = FcFontIdC { high: 0, low: 0 };
+
+    if found.is_null() {
+        return zero;
+    }
+    if result.is_null() {
+        unsafe { *found = false };
+        return zero;
+    }
+
+    unsafe {
+        let result = &*result;
+
+        let c = match char::from_u32(codepoint) {
+            Some(c) => c,
+            None => {
+                *found = false;
+                return zero;
+            }
+        };
+
+        for (id, unicode_ranges) in &result.ranked {
+            if unicode_ranges.iter().any(|range| range.contains(c)) {
+                *found = true;
+                return FcFontIdC::from_fontid(id);
+            }
+        }
+
+        *found = false;
+        zero
+    }
+}
+
+/// Free a ranked fallback list returned by `fc_font_sort`
+#[no_mangle]
+pub extern "C" fn fc_font_sort_free(result: *mut FcFontSortResult) {
+    if !result.is_null() {
+        unsafe {
+            let _ = Box::from_raw(result);
+        }
+    }
+}
+
+/// Compute the full candidate list for `pattern`'s base style, ordered best-to-worst (the same
+/// order `fc_font_sort` ranks internally, via `FcFontCache::build_fallback_order`), and return
+/// it as a flat array the caller can walk directly instead of going through the opaque
+/// `FcFontSortResult` handle. Useful when a caller wants to resolve fallbacks itself (e.g.
+/// against its own glyph cache) rather than asking `fc_font_sort_lookup` per codepoint. Writes
+/// the array length to `*out_count` and returns null (with `*out_count = 0`) if `cache` or
+/// `pattern` is null, or the pattern has no candidates at all.
+#[no_mangle]
+pub extern "C" fn fc_cache_query_sorted(
+    cache: *const FcFontCache,
+    pattern: *const FcPatternC,
+    out_count: *mut usize,
+) -> *mut FcFontIdC {
+    if out_count.is_null() {
+        return ptr::null_mut();
+    }
+    if cache.is_null() || pattern.is_null() {
+        unsafe { *out_count = 0 };
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let pattern_rust = c_to_pattern(pattern);
+
+        let order = cache.build_fallback_order(&pattern_rust);
+        if order.is_empty() {
+            *out_count = 0;
+            return ptr::null_mut();
+        }
+
+        let mut ids: Vec<FcFontIdC> = order.iter().map(FcFontIdC::from_fontid).collect();
+        *out_count = ids.len();
+        let ptr = ids.as_mut_ptr();
+        mem::forget(ids);
+
+        ptr
+    }
+}
+
+/// Free an array returned by `fc_cache_query_sorted`.
+#[no_mangle]
+pub extern "C" fn fc_font_ids_free(ids: *mut FcFontIdC, count: usize) {
+    if ids.is_null() {
+        return;
+    }
+
+    unsafe {
+        let _ = Vec::from_raw_parts(ids, count, count);
+    }
+}
+
+/// Opaque, owned coverage set backed by a sorted, merged list of `UnicodeRange`s, so
+/// `fc_charset_has_char` is a binary search rather than a linear scan of raw ranges.
+pub struct FcCharSetC {
+    ranges: Vec<UnicodeRange>,
+}
+
+/// Sort `ranges` by start and merge any that overlap or touch, so the result is the
+/// canonical form every `FcCharSetC` is stored in.
+fn merge_ranges(mut ranges: Vec<UnicodeRange>) -> Vec<UnicodeRange> {
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<UnicodeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        if let Some(last) = merged.last_mut() {
+            if range.start <= last.end.saturating_add(1) {
+                last.end = last.end.max(range.end);
+                continue;
+            }
+        }
+        merged.push(range);
+    }
+    merged
+}
+
+/// Subtract every range in `b` from every range in `a`. Both inputs must already be
+/// sorted and merged (the canonical `FcCharSetC` form).
+fn subtract_ranges(a: &[UnicodeRange], b: &[UnicodeRange]) -> Vec<UnicodeRange> {
+    let mut result = Vec::new();
+
+    for ra in a {
+        let mut start = ra.start;
+
+        for rb in b {
+            if rb.end < start || rb.start > ra.end {
+                continue;
+            }
+            if rb.start > start {
+                result.push(UnicodeRange {
+                    start,
+                    end: rb.start - 1,
+                });
+            }
+            if rb.end >= ra.end {
+                start = ra.end;
+                if start == u32::MAX {
+                    break;
+                }
+                start += 1;
+                break;
+            }
+            start = rb.end + 1;
+        }
+
+        if start <= ra.end {
+            result.push(UnicodeRange { start, end: ra.end });
+        }
+    }
+
+    result
+}
+
+/// Build a coverage set from a font's Unicode ranges
+#[no_mangle]
+pub extern "C" fn fc_font_charset(
+    cache: *const FcFontCache,
+    id: *const FcFontIdC,
+) -> *mut FcCharSetC {
+    if cache.is_null() || id.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let cache = &*cache;
+        let id_rust = FontId::from_fontid_c(&*id);
+
+        let pattern = match cache.get_metadata_by_id(&id_rust) {
+            Some(pattern) => pattern,
+            None => return ptr::null_mut(),
+        };
+
+        let ranges = merge_ranges(pattern.unicode_ranges.clone());
+        Box::into_raw(Box::new(FcCharSetC { ranges }))
+    }
+}
+
+/// Test whether `codepoint` is covered by `cs`
+#[no_mangle]
+pub extern "C" fn fc_charset_has_char(cs: *const FcCharSetC, codepoint: u32) -> bool {
+    if cs.is_null() {
+        return false;
+    }
+
+    unsafe {
+        let cs = &*cs;
+        let c = match char::from_u32(codepoint) {
+            Some(c) => c,
+            None => return false,
+        };
+
+        let idx = cs.ranges.partition_point(|r| r.end < codepoint);
+        cs.ranges.get(idx).is_some_and(|r| r.contains(c))
+    }
+}
+
+/// Count the total number of codepoints covered by `cs`
+#[no_mangle]
+pub extern "C" fn fc_charset_count(cs: *const FcCharSetC) -> usize {
+    if cs.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let cs = &*cs;
+        cs.ranges
+            .iter()
+            .map(|r| (r.end - r.start + 1) as u64)
+            .sum::<u64>() as usize
+    }
+}
+
+/// Return a new owned charset covering every codepoint in `a` or `b`
+#[no_mangle]
+pub extern "C" fn fc_charset_union(
+    a: *const FcCharSetC,
+    b: *const FcCharSetC,
+) -> *mut FcCharSetC {
+    if a.is_null() || b.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let a = &*a;
+        let b = &*b;
+
+        let mut combined = a.ranges.clone();
+        combined.extend(b.ranges.iter().copied());
+        let ranges = merge_ranges(combined);
+
+        Box::into_raw(Box::new(FcCharSetC { ranges }))
+    }
+}
+
+/// Return a new owned charset covering every codepoint in `a` that is not also in `b`
+#[no_mangle]
+pub extern "C" fn fc_charset_subtract(
+    a: *const FcCharSetC,
+    b: *const FcCharSetC,
+) -> *mut FcCharSetC {
+    if a.is_null() || b.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let a = &*a;
+        let b = &*b;
+
+        let ranges = subtract_ranges(&a.ranges, &b.ranges);
+        Box::into_raw(Box::new(FcCharSetC { ranges }))
+    }
+}
+
+/// Return a new owned charset covering every codepoint in any of the `count` charsets
+/// pointed to by `charsets`. This is the variadic counterpart to `fc_charset_union` for
+/// combining more than two sets in one call.
+#[no_mangle]
+pub extern "C" fn fc_charset_merge(
+    charsets: *const *const FcCharSetC,
+    count: usize,
+) -> *mut FcCharSetC {
+    if charsets.is_null() || count == 0 {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let charset_ptrs = slice::from_raw_parts(charsets, count);
+
+        let mut combined = Vec::new();
+        for &cs in charset_ptrs {
+            if cs.is_null() {
+                continue;
+            }
+            combined.extend((*cs).ranges.iter().copied());
+        }
+
+        let ranges = merge_ranges(combined);
+        Box::into_raw(Box::new(FcCharSetC { ranges }))
+    }
+}
+
+/// Free a charset returned by `fc_font_charset` or any `fc_charset_*` combinator
+#[no_mangle]
+pub extern "C" fn fc_charset_free(cs: *mut FcCharSetC) {
+    if !cs.is_null() {
+        unsafe {
+            let _ = Box::from_raw(cs);
+        }
+    }
+}
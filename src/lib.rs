@@ -87,20 +87,28 @@ extern crate alloc;
 
 use alloc::borrow::ToOwned;
 use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::{format, vec};
+use core::cell::RefCell;
+use core::fmt::Write as _;
+use core::ops::Range;
 use allsorts_subset_browser::binary::read::ReadScope;
-use allsorts_subset_browser::get_name::fontcode_get_name;
 use allsorts_subset_browser::tables::os2::Os2;
 use allsorts_subset_browser::tables::{FontTableProvider, HheaTable, HmtxTable, MaxpTable};
 use allsorts_subset_browser::tag;
 #[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
 use std::path::PathBuf;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
+#[cfg(feature = "std")]
+pub mod registry;
+
 /// UUID to identify a font (collections are broken up into separate fonts)
 #[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct FontId(pub u128);
@@ -162,10 +170,19 @@ impl FontId {
             FontId(id)
         }
     }
+
+    /// Sentinel `FontId` standing in for a bundled "tofu"/notdef face when
+    /// `FcFontCache::with_builtin_last_resort` is enabled and nothing else - not even a named
+    /// `last_resort_families` entry - covers a codepoint. Unlike every other `FontId`, this one
+    /// is never inserted into a cache's `patterns`/`metadata`/font-byte maps, so
+    /// `get_font_by_id`/`get_metadata_by_id` return `None` for it; callers must special-case it
+    /// the same way they'd special-case a shaping engine's own `.notdef` glyph, by drawing a
+    /// placeholder box rather than trying to load font data.
+    pub const BUILTIN_LAST_RESORT: FontId = FontId(u128::MAX);
 }
 
 /// Whether a field is required to match (yes / no / don't care)
-#[derive(Debug, Default, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub enum PatternMatch {
     /// Default: don't particularly care whether the requirement matches
@@ -191,8 +208,47 @@ impl PatternMatch {
     }
 }
 
+/// Spacing classification of a font's glyph advance widths, per fontconfig's `FC_SPACING`.
+///
+/// The existing `monospace: PatternMatch` field only distinguishes proportional from
+/// uniform-width fonts; `spacing` adds the finer fontconfig levels so terminal emulators can
+/// reject "dual-width" (e.g. half/full-width CJK) faces that `monospace` alone would accept.
+#[derive(Debug, Default, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub enum FcSpacing {
+    /// Default: don't particularly care about the spacing level
+    #[default]
+    DontCare,
+    /// Glyph advance widths vary freely (most text faces)
+    Proportional,
+    /// Two dominant advance widths, e.g. half/full-width CJK faces
+    Dual,
+    /// All glyphs share a single advance width
+    Mono,
+    /// Monospace with guaranteed uniform cell metrics, including box-drawing glyphs
+    CharCell,
+}
+
+impl FcSpacing {
+    /// True when this level is at least `Mono`, matching the legacy `monospace: PatternMatch`
+    /// boolean semantics ("true" meant "spacing is at least mono").
+    pub fn is_monospace(&self) -> bool {
+        matches!(self, FcSpacing::Mono | FcSpacing::CharCell)
+    }
+
+    /// Whether a face with spacing level `found` satisfies a request for `self`. `Mono` also
+    /// accepts `CharCell`; every other level requires an exact match.
+    fn satisfies(&self, found: FcSpacing) -> bool {
+        match self {
+            FcSpacing::DontCare => true,
+            FcSpacing::Mono => found.is_monospace(),
+            other => found == *other,
+        }
+    }
+}
+
 /// Font weight values as defined in CSS specification
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub enum FcWeight {
     Thin = 100,
@@ -512,6 +568,19 @@ impl UnicodeRange {
     }
 }
 
+/// How strictly `FcFontCache::query_with_strictness` matches style properties
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MatchStrictness {
+    /// Fall back to the closest weight/stretch/slant via `FcWeight::find_best_match` and
+    /// similar loosening, as `query`/`query_all` already do
+    #[default]
+    BestMatch,
+    /// Require `weight`, `stretch`, `italic` and `oblique` to match the stored face exactly;
+    /// return no match rather than substitute an approximate style. A pattern with
+    /// `FcPattern::exact_style` set behaves as if this were always passed to `query`/`query_all`.
+    ExactStyle,
+}
+
 /// Log levels for trace messages
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
 pub enum TraceLevel {
@@ -537,7 +606,10 @@ pub enum MatchReason {
         requested: String,
         found: String,
     },
-    WeightMismatch {
+    /// The candidate's weight didn't match the request exactly, but weight is no longer a
+    /// hard filter (see `FcFontCache::query_matches_internal`): `found` is still in the
+    /// running and gets ranked against other candidates by `weight_distance`.
+    WeightSubstituted {
         requested: FcWeight,
         found: FcWeight,
     },
@@ -545,10 +617,52 @@ pub enum MatchReason {
         requested: FcStretch,
         found: FcStretch,
     },
+    SpacingMismatch {
+        requested: FcSpacing,
+        found: FcSpacing,
+    },
     UnicodeRangeMismatch {
         character: char,
         ranges: Vec<UnicodeRange>,
     },
+    LanguageMismatch {
+        requested: Vec<String>,
+        covered: Vec<String>,
+    },
+    /// `pattern.required_scripts` named at least one OpenType script tag (e.g. `arab`, `hani`)
+    /// the candidate's own `script_coverage` doesn't contain; see `FcFontCache::fonts_for_script`.
+    ScriptMismatch {
+        requested: Vec<[u8; 4]>,
+        covered: Vec<[u8; 4]>,
+    },
+    /// A `<match target="pattern">` rule from a loaded fontconfig config rewrote the
+    /// requested pattern before matching began; see `FcMatchRule`.
+    Substituted {
+        rule: String,
+    },
+    /// `pattern.fullname` resolved by progressively stripping trailing words off as style/
+    /// weight tokens until the remaining family prefix matched; see
+    /// `FcFontCache::query_fullname`.
+    FullNameResolved {
+        requested: String,
+        matched_family: String,
+        style_tokens: Vec<String>,
+    },
+    /// A candidate otherwise matched, but `pattern.exact_style` (or `MatchStrictness::
+    /// ExactStyle`) required weight/stretch/italic/oblique to match the stored face exactly,
+    /// and at least one of them didn't - so no closest-match substitution was attempted.
+    ExactStyleMismatch {
+        requested: String,
+        found: String,
+    },
+    /// A candidate's identity requirements (name/family/monospace/spacing) matched and it was
+    /// ranked by style instead of being rejected outright; `distance` is the score it was
+    /// ranked by: the combined `FcPattern::find_best_match` score in
+    /// `FcFontRegistry::resolve_font_chain_uncached`'s generic-family branch, or the
+    /// `calculate_style_score` value in `FcFontCache::query_sorted`.
+    StyleScored {
+        distance: i32,
+    },
     Success,
 }
 
@@ -560,6 +674,173 @@ pub struct TraceMsg {
     pub reason: MatchReason,
 }
 
+/// Per-axis score `FcFontCache::query_sorted` ranks candidates by, the same components
+/// `query`/`query_all` already collapse into a single ordering, exposed individually so a
+/// caller can apply its own tie-breaks (mirroring the per-candidate scores fontconfig's
+/// `FcFontSort` reports alongside its ranked `FcFontSet`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MatchScore {
+    /// Summed per-language tier (exact tag match, then primary-subtag match, then no match)
+    /// across `pattern.languages`, see `calculate_language_score`. Higher is better.
+    pub language_score: usize,
+    /// Weighted distance across weight/stretch/italic/oblique/monospace/condensed, see
+    /// `calculate_style_score`. Lower is better.
+    pub style_score: i32,
+    /// Total codepoints the candidate's `unicode_ranges` cover. Higher is better.
+    pub unicode_coverage: u64,
+}
+
+/// Where a matched font's bytes actually come from, reported by `explain_query` so a caller
+/// can tell a real installed font from an `with_memory_fonts` test double apart in the
+/// diagnostic output. Distinct from the borrowing `FontSource<'a>` returned by
+/// `get_font_by_id`/`get_font_bytes` - this one is owned, since `MatchExplanation` is returned
+/// by value rather than borrowing from the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchSource {
+    /// Loaded from disk; `font_index` is the face's index within a font collection (`.ttc`).
+    Disk { path: String, font_index: usize },
+    /// Registered directly via `with_memory_fonts`/`add_memory_font`, not backed by a path.
+    Memory,
+}
+
+/// One candidate `explain_query` considered, in final rank order - the provenance/diagnostic
+/// counterpart to `query`'s single winning `FontId`, mirroring what an `fc-match -v`-style
+/// dump would show for each candidate it tried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchExplanation {
+    pub id: FontId,
+    /// The stored metadata pattern this candidate matched with.
+    pub pattern: FcPattern,
+    pub score: MatchScore,
+    /// Human-readable account of which pattern field(s) this candidate matched on, e.g.
+    /// `"exact family match"` or `"unicode-coverage fallback"`.
+    pub reason: String,
+    pub source: MatchSource,
+}
+
+/// A single `<test name="family">..</test>` condition within a `<match target="pattern">`
+/// fontconfig rule. Scoped to family-name equality, the one test this crate's single-family
+/// `FcPattern` model has a direct analogue for; other fontconfig test names parsed from config
+/// are ignored rather than rejecting the whole rule (same "keep going" spirit as
+/// `unknown_properties`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FcSubstTest {
+    pub family: String,
+}
+
+impl FcSubstTest {
+    fn matches(&self, pattern: &FcPattern) -> bool {
+        pattern
+            .family
+            .as_deref()
+            .map_or(false, |f| f.eq_ignore_ascii_case(&self.family))
+            || pattern
+                .name
+                .as_deref()
+                .map_or(false, |f| f.eq_ignore_ascii_case(&self.family))
+    }
+}
+
+/// How a fontconfig `<edit>` combines its value with whatever the pattern already carries.
+/// `Assign` always overrides; `Prepend`/`Append` only fill in a value the pattern left at its
+/// default, mirroring fontconfig's weak (`FcDefaultSubstitute`-like) edits without needing a
+/// list-valued property to prepend/append onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FcEditMode {
+    Assign,
+    Prepend,
+    Append,
+}
+
+/// One `<edit>` inside a `<match>` block, scoped to the `FcPattern` fields fontconfig configs
+/// commonly rewrite for style selection - weight, slant and spacing - rather than every
+/// property fontconfig itself supports. Family-list substitution (`sans-serif` -> a preferred
+/// concrete family) is already covered by `<alias>`/`generic_aliases`, which fits this crate's
+/// list-of-substitutes model better than a single-valued `<edit name="family">` would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FcSubstEdit {
+    Weight { mode: FcEditMode, value: FcWeight },
+    Slant { mode: FcEditMode, value: FcSlant },
+    Spacing { mode: FcEditMode, value: FcSpacing },
+}
+
+/// The three slant values fontconfig's `slant` property can hold, i.e. the `<const>` values
+/// `roman`/`italic`/`oblique` in a `<match>` rule's `<edit name="slant">`. `FcPattern` itself
+/// models `italic`/`oblique` as two independent `PatternMatch` flags rather than one enum, so
+/// this is only used while parsing/applying a substitution edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FcSlant {
+    Roman,
+    Italic,
+    Oblique,
+}
+
+/// A parsed `<match target="pattern"> <test>...</test>* <edit>...</edit>+ </match>` rule,
+/// applied by `FcFontCache::query`/`query_all`/`query_with_strictness` before matching -
+/// mirrors fontconfig's `FcConfigSubstitute` phase. Loaded from a config file by
+/// `FcFontCache::build_with_config`, and by `FcFontCache::build` on Linux from the system's
+/// `/etc/fonts/fonts.conf`; otherwise empty (and a no-op).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FcMatchRule {
+    pub tests: Vec<FcSubstTest>,
+    pub edits: Vec<FcSubstEdit>,
+}
+
+impl FcMatchRule {
+    fn matches(&self, pattern: &FcPattern) -> bool {
+        self.tests.iter().all(|t| t.matches(pattern))
+    }
+
+    fn apply(&self, pattern: &mut FcPattern) {
+        for edit in &self.edits {
+            match edit {
+                FcSubstEdit::Weight { mode, value } => match mode {
+                    FcEditMode::Assign => pattern.weight = *value,
+                    FcEditMode::Prepend | FcEditMode::Append => {
+                        if pattern.weight == FcWeight::Normal {
+                            pattern.weight = *value;
+                        }
+                    }
+                },
+                FcSubstEdit::Slant { mode, value } => {
+                    let already_set =
+                        pattern.italic != PatternMatch::DontCare || pattern.oblique != PatternMatch::DontCare;
+                    if *mode == FcEditMode::Assign || !already_set {
+                        pattern.italic = PatternMatch::False;
+                        pattern.oblique = PatternMatch::False;
+                        match value {
+                            FcSlant::Roman => {}
+                            FcSlant::Italic => pattern.italic = PatternMatch::True,
+                            FcSlant::Oblique => pattern.oblique = PatternMatch::True,
+                        }
+                    }
+                }
+                FcSubstEdit::Spacing { mode, value } => match mode {
+                    FcEditMode::Assign => {
+                        pattern.spacing = *value;
+                        if value.is_monospace() {
+                            pattern.monospace = PatternMatch::True;
+                        }
+                    }
+                    FcEditMode::Prepend | FcEditMode::Append => {
+                        if pattern.spacing == FcSpacing::DontCare {
+                            pattern.spacing = *value;
+                            if value.is_monospace() {
+                                pattern.monospace = PatternMatch::True;
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    /// Short human-readable description for `MatchReason::Substituted` trace entries.
+    fn describe(&self) -> String {
+        format!("{} test(s) -> {} edit(s)", self.tests.len(), self.edits.len())
+    }
+}
+
 /// Font pattern for matching
 #[derive(Default, Clone, PartialOrd, Ord, PartialEq, Eq)]
 #[repr(C)]
@@ -568,6 +849,12 @@ pub struct FcPattern {
     pub name: Option<String>,
     // family name
     pub family: Option<String>,
+    /// A full display name as users actually type it - e.g. "Arial Bold", "Fira Code Retina" -
+    /// rather than separate family + weight/style fields. When set, `FcFontCache::query` tries
+    /// it whole against `name`/`family` first, then progressively strips trailing words and
+    /// reinterprets them as style/weight tokens (see `FcFontCache::query_fullname`), so this
+    /// takes precedence over `name`/`family` for that call.
+    pub fullname: Option<String>,
     // "italic" property
     pub italic: PatternMatch,
     // "oblique" property
@@ -576,16 +863,87 @@ pub struct FcPattern {
     pub bold: PatternMatch,
     // "monospace" property
     pub monospace: PatternMatch,
+    // finer-grained spacing level; `monospace: True` implies `spacing` is at least `Mono`
+    pub spacing: FcSpacing,
+    /// Whether the face has serifs, classified at scan time by `classify_serif` from the OS/2
+    /// PANOSE `bSerifStyle` byte, or a built-in family-name heuristic when PANOSE doesn't
+    /// classify the face one way or the other. `DontCare` for an unclassified face or a bare
+    /// query pattern. `FcFontCache::query`/`query_all` set this to `True`/`False` when
+    /// resolving the `serif`/`sans-serif` generic families (see `query_generic_family`).
+    pub serif: PatternMatch,
     // "condensed" property
     pub condensed: PatternMatch,
     // font weight
     pub weight: FcWeight,
+    /// Exact numeric weight, e.g. an OS/2 `usWeightClass` of `430` or a variable font `wght`
+    /// instance, that doesn't land on one of `FcWeight`'s nine buckets. On a query pattern,
+    /// when set this overrides `weight` for scoring: `FcFontCache::query`/`query_all` pick the
+    /// candidate minimizing `|weight_value - candidate's real weight|` instead of snapping to
+    /// the closest `FcWeight` via `FcWeight::find_best_match`. On a stored face's metadata,
+    /// this is the real OS/2 `usWeightClass` the enum was bucketed from. `None` falls back to
+    /// the coarse `weight` enum in both cases.
+    pub weight_value: Option<u16>,
+    /// `(min, default, max)` of the face's variable `wght` axis, recorded during `build` from
+    /// the `fvar` table. `None` for a static face or a bare query pattern. A query's
+    /// `weight_value` lying inside this range is an exact match (distance `0`): the font can
+    /// be instantiated at that weight directly rather than snapping to the nearest static
+    /// instance - see `FontMatch::instantiated_weight`.
+    pub weight_axis: Option<(u16, u16, u16)>,
     // font stretch
     pub stretch: FcStretch,
+    /// When set, `FcFontCache::query`/`query_all` require `weight`, `stretch`, `italic` and
+    /// `oblique` to match a stored face exactly rather than substituting the closest available
+    /// style via `FcWeight::find_best_match` - equivalent to always calling
+    /// `query_with_strictness` with `MatchStrictness::ExactStyle` for this pattern. For tools
+    /// that must verify a specific face exists (e.g. embedding exactly "SemiBold") rather than
+    /// accept whatever renders closest.
+    pub exact_style: bool,
     // unicode ranges to match
     pub unicode_ranges: Vec<UnicodeRange>,
+    // actual per-glyph `cmap` coverage, as a sorted run-length list of covered codepoints -
+    // distinct from `unicode_ranges` above (which are only the coarse OS/2 block hints a
+    // font *advertises*). Empty when the `cmap` table couldn't be read, in which case
+    // `contains_char` falls back to `unicode_ranges`.
+    pub cmap_coverage: Vec<UnicodeRange>,
+    /// OpenType script tags (e.g. `b"cyrl"`, `b"hani"`, `b"arab"`) this face's `cmap` coverage
+    /// maps to, precomputed during `build` by `extract_script_coverage` - coarser than
+    /// `cmap_coverage` (whole scripts, not individual codepoints) but fast to intersect for
+    /// `fonts_for_script` and the `required_scripts` check below. Empty for patterns that
+    /// weren't scanned from a real font (e.g. a bare query pattern).
+    pub script_coverage: Vec<[u8; 4]>,
     // extended font metadata
     pub metadata: FcFontMetadata,
+    /// The font's foundry, e.g. `"adobe"` or `"urw"` - read from the OS/2 `achVendID` tag at
+    /// scan time (see `FcParseFont`), falling back to a notice-substring heuristic against
+    /// `metadata.copyright`/`metadata.trademark` when `achVendID` is empty or absent. `None`
+    /// for a bare query pattern or a face where neither source yielded anything.
+    pub foundry: Option<String>,
+    // BCP-47 language tags this pattern requests or that the face supports
+    pub languages: Vec<String>,
+    /// OpenType script tags a query requires the candidate's own `script_coverage` to
+    /// contain - e.g. `[*b"arab"]` to ask "can this font shape Arabic?" directly rather than
+    /// probing individual codepoints. Empty (the default) imposes no requirement. Unlike
+    /// `languages`, a candidate with empty `script_coverage` is rejected rather than let
+    /// through, since the absence of scanned coverage isn't a guarantee the face can shape it.
+    pub required_scripts: Vec<[u8; 4]>,
+    /// Whether the face has true vector outlines (a `glyf` or `CFF ` table) rather than only
+    /// fixed-size bitmap strikes - scanned during `build` from the face's table directory.
+    /// `DontCare` (the default) imposes no requirement; a bare query pattern that was never
+    /// scanned from a real font also leaves this at `DontCare`.
+    pub scalable: PatternMatch,
+    /// Same scan-time signal as `scalable` under fontconfig's separate `FC_OUTLINE`
+    /// property name - true when the face is rendered from outlines instead of embedded
+    /// bitmaps. In practice this always tracks `scalable`, since this crate classifies any
+    /// face with `glyf`/`CFF ` outlines as both scalable and outline-rendered.
+    pub outline: PatternMatch,
+    /// Whether the face carries embedded bitmap strikes (an `EBDT`/`CBDT` table) that a
+    /// renderer could use instead of scaling outlines - set independently of `scalable`
+    /// since a font can carry both outlines and bitmap strikes (or, rarely, bitmap strikes
+    /// only).
+    pub embedded_bitmap: PatternMatch,
+    // properties seen while parsing a fontconfig pattern string that aren't
+    // otherwise represented on `FcPattern` (preserved instead of rejected)
+    pub unknown_properties: Vec<(String, String)>,
 }
 
 impl core::fmt::Debug for FcPattern {
@@ -600,6 +958,10 @@ impl core::fmt::Debug for FcPattern {
             d.field("family", family);
         }
 
+        if let Some(fullname) = &self.fullname {
+            d.field("fullname", fullname);
+        }
+
         if self.italic != PatternMatch::DontCare {
             d.field("italic", &self.italic);
         }
@@ -616,32 +978,406 @@ impl core::fmt::Debug for FcPattern {
             d.field("monospace", &self.monospace);
         }
 
+        if self.spacing != FcSpacing::DontCare {
+            d.field("spacing", &self.spacing);
+        }
+
         if self.condensed != PatternMatch::DontCare {
             d.field("condensed", &self.condensed);
         }
 
+        if self.serif != PatternMatch::DontCare {
+            d.field("serif", &self.serif);
+        }
+
         if self.weight != FcWeight::Normal {
             d.field("weight", &self.weight);
         }
 
+        if let Some(weight_value) = self.weight_value {
+            d.field("weight_value", &weight_value);
+        }
+
+        if let Some(weight_axis) = self.weight_axis {
+            d.field("weight_axis", &weight_axis);
+        }
+
         if self.stretch != FcStretch::Normal {
             d.field("stretch", &self.stretch);
         }
 
+        if self.exact_style {
+            d.field("exact_style", &self.exact_style);
+        }
+
         if !self.unicode_ranges.is_empty() {
             d.field("unicode_ranges", &self.unicode_ranges);
         }
 
+        if !self.cmap_coverage.is_empty() {
+            d.field("cmap_coverage", &self.cmap_coverage);
+        }
+
+        if !self.script_coverage.is_empty() {
+            d.field("script_coverage", &self.script_coverage);
+        }
+
         // Only show non-empty metadata fields
         let empty_metadata = FcFontMetadata::default();
         if self.metadata != empty_metadata {
             d.field("metadata", &self.metadata);
         }
 
+        if let Some(foundry) = &self.foundry {
+            d.field("foundry", foundry);
+        }
+
+        if !self.languages.is_empty() {
+            d.field("languages", &self.languages);
+        }
+
+        if !self.required_scripts.is_empty() {
+            d.field("required_scripts", &self.required_scripts);
+        }
+
+        if self.scalable != PatternMatch::DontCare {
+            d.field("scalable", &self.scalable);
+        }
+
+        if self.outline != PatternMatch::DontCare {
+            d.field("outline", &self.outline);
+        }
+
+        if self.embedded_bitmap != PatternMatch::DontCare {
+            d.field("embedded_bitmap", &self.embedded_bitmap);
+        }
+
+        if !self.unknown_properties.is_empty() {
+            d.field("unknown_properties", &self.unknown_properties);
+        }
+
         d.finish()
     }
 }
 
+/// Error returned by [`FcPattern::parse`] when a recognized property has an unparsable value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FcPatternParseError {
+    /// The `weight=` value was neither a known keyword nor a number in `1..=1000`
+    InvalidWeight(String),
+    /// The `width=` value was neither a known keyword nor a number in `1..=9`
+    InvalidWidth(String),
+}
+
+impl core::fmt::Display for FcPatternParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FcPatternParseError::InvalidWeight(s) => write!(f, "invalid weight value: {:?}", s),
+            FcPatternParseError::InvalidWidth(s) => write!(f, "invalid width value: {:?}", s),
+        }
+    }
+}
+
+impl FcPattern {
+    /// Parses a fontconfig-style pattern string, e.g.
+    /// `"DejaVu Sans Mono:style=Bold:weight=200:slant=italic"` or `"monospace:pixelsize=11"`.
+    ///
+    /// Grammar: an optional comma-separated family list before the first `:`, followed by
+    /// zero or more `:`-separated `key=value` (or `key=val1,val2`) property assignments.
+    /// Unrecognized keys are kept in `unknown_properties` instead of causing an error.
+    pub fn parse(s: &str) -> Result<Self, FcPatternParseError> {
+        let mut pattern = FcPattern::default();
+
+        let mut parts = s.split(':');
+
+        if let Some(families) = parts.next() {
+            let families = families.trim();
+            if !families.is_empty() {
+                if let Some(first) = families.split(',').map(|f| f.trim().to_string()).next() {
+                    pattern.family = Some(first.clone());
+                    pattern.name = Some(first);
+                }
+            }
+        }
+
+        for part in parts {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+
+            let (key, value) = match part.split_once('=') {
+                Some((k, v)) => (k.trim(), v.trim()),
+                None => {
+                    pattern
+                        .unknown_properties
+                        .push((part.to_string(), String::new()));
+                    continue;
+                }
+            };
+
+            match key {
+                "family" => {
+                    if let Some(first) = value.split(',').next() {
+                        pattern.family = Some(first.trim().to_string());
+                    }
+                }
+                "style" => {
+                    // Keep the raw style string (fontconfig surfaces it as its own property
+                    // alongside the weight/slant it implies), and also fold recognized tokens
+                    // (space-separated, e.g. "Bold Italic") onto the fields those queries
+                    // actually match against.
+                    pattern.name = Some(value.to_string());
+                    for token in value.split_whitespace() {
+                        match token.to_ascii_lowercase().as_str() {
+                            "bold" => {
+                                pattern.weight = FcWeight::Bold;
+                                pattern.bold = PatternMatch::True;
+                            }
+                            "italic" => pattern.italic = PatternMatch::True,
+                            "oblique" => pattern.oblique = PatternMatch::True,
+                            "book" | "regular" | "normal" => {}
+                            _ => {}
+                        }
+                    }
+                }
+                "weight" => pattern.weight = parse_fc_weight(value)?,
+                "width" => pattern.stretch = parse_fc_stretch(value)?,
+                "slant" => match value {
+                    "italic" => pattern.italic = PatternMatch::True,
+                    "oblique" => pattern.oblique = PatternMatch::True,
+                    "roman" | "normal" => {}
+                    _ => pattern
+                        .unknown_properties
+                        .push((key.to_string(), value.to_string())),
+                },
+                "spacing" => match parse_fc_spacing(value) {
+                    Some(spacing) => {
+                        pattern.spacing = spacing;
+                        if spacing.is_monospace() {
+                            pattern.monospace = PatternMatch::True;
+                        }
+                    }
+                    None => pattern
+                        .unknown_properties
+                        .push((key.to_string(), value.to_string())),
+                },
+                "lang" => {
+                    pattern
+                        .languages
+                        .extend(value.split(',').map(|v| v.trim().to_string()));
+                }
+                "script" => {
+                    for tag in value.split(',') {
+                        let tag = tag.trim();
+                        if tag.len() == 4 {
+                            let mut bytes = [0u8; 4];
+                            bytes.copy_from_slice(tag.as_bytes());
+                            pattern.required_scripts.push(bytes);
+                        } else {
+                            pattern
+                                .unknown_properties
+                                .push((key.to_string(), tag.to_string()));
+                        }
+                    }
+                }
+                "charset" => {
+                    for cp in value.split(',') {
+                        let cp = cp.trim().trim_start_matches("0x");
+                        if let Ok(code) = u32::from_str_radix(cp, 16) {
+                            pattern.unicode_ranges.push(UnicodeRange {
+                                start: code,
+                                end: code,
+                            });
+                        }
+                    }
+                }
+                _ => pattern
+                    .unknown_properties
+                    .push((key.to_string(), value.to_string())),
+            }
+        }
+
+        Ok(pattern)
+    }
+
+    /// Renders this pattern back into fontconfig's `family:key=value:...` string syntax.
+    pub fn to_fc_string(&self) -> String {
+        let mut s = String::new();
+
+        if let Some(family) = self.family.as_ref().or(self.name.as_ref()) {
+            s.push_str(family);
+        }
+
+        if self.weight != FcWeight::Normal {
+            let _ = write!(s, ":weight={}", self.weight as u16);
+        }
+
+        if self.stretch != FcStretch::Normal {
+            let _ = write!(s, ":width={}", self.stretch as u16);
+        }
+
+        if self.italic == PatternMatch::True {
+            s.push_str(":slant=italic");
+        } else if self.oblique == PatternMatch::True {
+            s.push_str(":slant=oblique");
+        }
+
+        if self.spacing != FcSpacing::DontCare {
+            let spacing_str = match self.spacing {
+                FcSpacing::Proportional => "proportional",
+                FcSpacing::Dual => "dual",
+                FcSpacing::Mono => "mono",
+                FcSpacing::CharCell => "charcell",
+                FcSpacing::DontCare => unreachable!(),
+            };
+            let _ = write!(s, ":spacing={}", spacing_str);
+        } else if self.monospace == PatternMatch::True {
+            s.push_str(":spacing=mono");
+        }
+
+        if !self.languages.is_empty() {
+            let _ = write!(s, ":lang={}", self.languages.join(","));
+        }
+
+        if !self.required_scripts.is_empty() {
+            let scripts: Vec<String> = self
+                .required_scripts
+                .iter()
+                .map(|tag| String::from_utf8_lossy(tag).to_string())
+                .collect();
+            let _ = write!(s, ":script={}", scripts.join(","));
+        }
+
+        for (key, value) in &self.unknown_properties {
+            if value.is_empty() {
+                let _ = write!(s, ":{}", key);
+            } else {
+                let _ = write!(s, ":{}={}", key, value);
+            }
+        }
+
+        s
+    }
+}
+
+impl core::fmt::Display for FcPattern {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_fc_string())
+    }
+}
+
+fn parse_fc_weight(value: &str) -> Result<FcWeight, FcPatternParseError> {
+    match value.to_ascii_lowercase().as_str() {
+        "thin" => Ok(FcWeight::Thin),
+        "extralight" => Ok(FcWeight::ExtraLight),
+        "light" => Ok(FcWeight::Light),
+        "regular" | "normal" => Ok(FcWeight::Normal),
+        "medium" => Ok(FcWeight::Medium),
+        "semibold" | "demibold" => Ok(FcWeight::SemiBold),
+        "bold" => Ok(FcWeight::Bold),
+        "extrabold" => Ok(FcWeight::ExtraBold),
+        "black" | "heavy" => Ok(FcWeight::Black),
+        other => other
+            .parse::<u16>()
+            .ok()
+            .filter(|w| (1..=1000).contains(w))
+            .map(FcWeight::from_u16)
+            .ok_or_else(|| FcPatternParseError::InvalidWeight(value.to_string())),
+    }
+}
+
+fn parse_fc_spacing(value: &str) -> Option<FcSpacing> {
+    match value.to_ascii_lowercase().as_str() {
+        "proportional" => Some(FcSpacing::Proportional),
+        "dual" => Some(FcSpacing::Dual),
+        "mono" | "monospace" => Some(FcSpacing::Mono),
+        "charcell" => Some(FcSpacing::CharCell),
+        _ => None,
+    }
+}
+
+fn parse_fc_stretch(value: &str) -> Result<FcStretch, FcPatternParseError> {
+    match value.to_ascii_lowercase().as_str() {
+        "ultracondensed" => Ok(FcStretch::UltraCondensed),
+        "extracondensed" => Ok(FcStretch::ExtraCondensed),
+        "condensed" => Ok(FcStretch::Condensed),
+        "semicondensed" => Ok(FcStretch::SemiCondensed),
+        "normal" => Ok(FcStretch::Normal),
+        "semiexpanded" => Ok(FcStretch::SemiExpanded),
+        "expanded" => Ok(FcStretch::Expanded),
+        "extraexpanded" => Ok(FcStretch::ExtraExpanded),
+        "ultraexpanded" => Ok(FcStretch::UltraExpanded),
+        other => other
+            .parse::<u16>()
+            .ok()
+            .filter(|w| (1..=9).contains(w))
+            .map(FcStretch::from_u16)
+            .ok_or_else(|| FcPatternParseError::InvalidWidth(value.to_string())),
+    }
+}
+
+/// Returns `true` for codepoints that extend the *previous* grapheme cluster rather than
+/// starting a new one - combining marks, the zero-width joiner, variation selectors and
+/// emoji skin-tone modifiers. A pragmatic approximation of UAX #29's extended grapheme
+/// cluster boundaries covering what `query_for_text_clustered` needs to keep together
+/// (combining accents, ZWJ emoji sequences, skin-tone modifiers), not a full implementation
+/// of the Unicode text segmentation algorithm.
+fn is_grapheme_extend(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F // Combining Diacritical Marks
+        | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+        | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+        | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+        | 0xFE00..=0xFE0F // Variation Selectors
+        | 0xFE20..=0xFE2F // Combining Half Marks
+        | 0x200D // Zero-Width Joiner
+        | 0x1F3FB..=0x1F3FF // Emoji skin-tone modifiers
+    )
+}
+
+/// Whether `c` is a regional-indicator symbol (`U+1F1E6..=U+1F1FF`); two of these in a row
+/// form a flag emoji and are kept in one cluster by `grapheme_cluster_ranges`.
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Splits `text` into extended-grapheme-cluster-ish byte ranges for `query_for_text_
+/// clustered`: a base codepoint followed by any combining-mark/ZWJ/variation-selector/
+/// skin-tone-modifier continuations (see `is_grapheme_extend`), with regional-indicator
+/// pairs (flag emoji) kept together as a single base.
+fn grapheme_cluster_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+
+        if is_regional_indicator(c) {
+            if let Some(&(next_start, next_c)) = chars.peek() {
+                if is_regional_indicator(next_c) {
+                    end = next_start + next_c.len_utf8();
+                    chars.next();
+                }
+            }
+        }
+
+        while let Some(&(next_start, next_c)) = chars.peek() {
+            if is_grapheme_extend(next_c) {
+                end = next_start + next_c.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        ranges.push(start..end);
+    }
+
+    ranges
+}
+
 /// Font metadata from the OS/2 table
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FcFontMetadata {
@@ -662,11 +1398,28 @@ pub struct FcFontMetadata {
     pub trademark: Option<String>,
     pub unique_id: Option<String>,
     pub version: Option<String>,
+    /// `head`/`hhea`/OS-2-derived vertical metrics, used for fallback-metric-override
+    /// computation. Zeroed if the face's tables couldn't be read for this.
+    pub metrics: FontMetrics,
 }
 
 impl FcPattern {
-    /// Check if this pattern would match the given character
+    /// Check if this pattern would match the given character. Prefers the real `cmap`
+    /// coverage when it was successfully parsed, since the coarse `unicode_ranges` OS/2
+    /// hints can claim a block a font doesn't fully populate; falls back to those hints
+    /// for fonts (or synthetic patterns) that have no `cmap_coverage` recorded. `cmap_coverage`
+    /// is sorted and merged (see `extract_cmap_coverage`), so this is a binary search rather
+    /// than a linear scan over every range.
     pub fn contains_char(&self, c: char) -> bool {
+        if !self.cmap_coverage.is_empty() {
+            let codepoint = c as u32;
+            let idx = self.cmap_coverage.partition_point(|r| r.end < codepoint);
+            return self
+                .cmap_coverage
+                .get(idx)
+                .is_some_and(|r| r.contains(c));
+        }
+
         if self.unicode_ranges.is_empty() {
             return true; // No ranges specified means match all characters
         }
@@ -679,6 +1432,124 @@ impl FcPattern {
 
         false
     }
+
+    /// Picks the single closest-matching candidate out of `candidates` using the CSS Fonts
+    /// cascading axis-priority algorithm: stretch distance is compared first, then slant
+    /// distance, then weight distance - the same order a browser falls back through
+    /// `font-stretch`, then `font-style`, then `font-weight` once `font-family` has already
+    /// narrowed things down to one face family. Returns the winning `FontId` together with
+    /// its combined distance (lower is closer, `0` is an exact style match on all three
+    /// axes), or `None` if `candidates` is empty.
+    pub fn find_best_match(&self, candidates: &[(FontId, FcPattern)]) -> Option<(FontId, i32)> {
+        candidates
+            .iter()
+            .map(|(id, candidate)| (*id, Self::axis_distance(self, candidate)))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(id, (stretch_d, slant_d, weight_d))| {
+                (id, stretch_d * 1_000_000 + slant_d * 1_000 + weight_d)
+            })
+    }
+
+    /// The `(stretch, slant, weight)` distance triple `find_best_match` ranks candidates by,
+    /// compared lexicographically so stretch dominates slant which dominates weight.
+    fn axis_distance(&self, candidate: &FcPattern) -> (i32, i32, i32) {
+        (
+            Self::stretch_distance(self.stretch, candidate.stretch),
+            Self::slant_distance(Self::effective_slant(self), Self::effective_slant(candidate)),
+            Self::weight_distance(self.weight, candidate.weight),
+        )
+    }
+
+    /// Follows the same narrower-first/wider-first tie rule as `FcStretch::find_best_match`,
+    /// but as a magnitude instead of a yes/no pick: any candidate on the preferred side of
+    /// `requested` always outranks any candidate on the other side.
+    fn stretch_distance(requested: FcStretch, candidate: FcStretch) -> i32 {
+        if requested == candidate {
+            return 0;
+        }
+
+        let requested_v = requested as i32;
+        let candidate_v = candidate as i32;
+        // Always ranks behind any candidate on the preferred side (max possible diff is 8).
+        const OFF_SIDE_PENALTY: i32 = 100;
+
+        if requested <= FcStretch::Normal {
+            if candidate_v < requested_v {
+                requested_v - candidate_v
+            } else {
+                OFF_SIDE_PENALTY + (candidate_v - requested_v)
+            }
+        } else if candidate_v > requested_v {
+            candidate_v - requested_v
+        } else {
+            OFF_SIDE_PENALTY + (requested_v - candidate_v)
+        }
+    }
+
+    /// The effective upright/oblique/italic slant of a pattern, derived from its independent
+    /// `italic`/`oblique` flags (see `FcSlant`'s doc comment for why there's no single field).
+    fn effective_slant(pattern: &FcPattern) -> FcSlant {
+        if pattern.italic == PatternMatch::True {
+            FcSlant::Italic
+        } else if pattern.oblique == PatternMatch::True {
+            FcSlant::Oblique
+        } else {
+            FcSlant::Roman
+        }
+    }
+
+    /// CSS font-style fallback order: a request for one slant prefers the other slanted
+    /// value before falling all the way back to upright, e.g. `italic` -> `oblique` ->
+    /// `roman`, mirroring how browsers resolve `font-style: italic` against a family that
+    /// only ships an oblique face.
+    fn slant_distance(requested: FcSlant, candidate: FcSlant) -> i32 {
+        let preference = match requested {
+            FcSlant::Roman => [FcSlant::Roman, FcSlant::Oblique, FcSlant::Italic],
+            FcSlant::Italic => [FcSlant::Italic, FcSlant::Oblique, FcSlant::Roman],
+            FcSlant::Oblique => [FcSlant::Oblique, FcSlant::Italic, FcSlant::Roman],
+        };
+        preference
+            .iter()
+            .position(|slant| *slant == candidate)
+            .unwrap_or(preference.len()) as i32
+    }
+
+    /// Weight distance with the same 400/500 special-casing `FcWeight::find_best_match`
+    /// uses (a `Normal` request prefers `Medium` before any other weight, and vice versa),
+    /// falling back to a plain magnitude comparison on the preferred side otherwise.
+    fn weight_distance(requested: FcWeight, candidate: FcWeight) -> i32 {
+        if requested == candidate {
+            return 0;
+        }
+
+        let requested_v = requested as i32;
+        let candidate_v = candidate as i32;
+        // Always ranks behind any candidate on the preferred side (max possible diff is 800).
+        const OFF_SIDE_PENALTY: i32 = 1_000;
+
+        match requested {
+            FcWeight::Normal if candidate == FcWeight::Medium => 1,
+            FcWeight::Medium if candidate == FcWeight::Normal => 1,
+            FcWeight::Normal | FcWeight::Thin | FcWeight::ExtraLight | FcWeight::Light => {
+                if candidate_v <= requested_v {
+                    requested_v - candidate_v
+                } else {
+                    OFF_SIDE_PENALTY + (candidate_v - requested_v)
+                }
+            }
+            FcWeight::Medium
+            | FcWeight::SemiBold
+            | FcWeight::Bold
+            | FcWeight::ExtraBold
+            | FcWeight::Black => {
+                if candidate_v >= requested_v {
+                    candidate_v - requested_v
+                } else {
+                    OFF_SIDE_PENALTY + (requested_v - candidate_v)
+                }
+            }
+        }
+    }
 }
 
 /// Font match result with UUID
@@ -687,21 +1558,241 @@ pub struct FontMatch {
     pub id: FontId,
     pub unicode_ranges: Vec<UnicodeRange>,
     pub fallbacks: Vec<FontMatchNoFallback>,
+    /// Faux styling a rasterizer should apply because no exact face was found.
+    /// Zeroed when `id`'s face already matches the requested weight/slant exactly.
+    pub synthesis: FontSynthesis,
+    /// When the query's `weight_value` landed inside `id`'s variable `wght` axis
+    /// (`FcPattern::weight_axis`), the exact coordinate the caller should instantiate the
+    /// variable font at to get that precise weight. `None` for a static face, or when the
+    /// request didn't carry a `weight_value` at all.
+    pub instantiated_weight: Option<u16>,
 }
 
-/// Font match result with UUID (without fallback)
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct FontMatchNoFallback {
-    pub id: FontId,
-    pub unicode_ranges: Vec<UnicodeRange>,
-}
-
-/// Path to a font file
-#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq)]
-#[repr(C)]
-pub struct FcFontPath {
-    pub path: String,
-    pub font_index: usize,
+/// Synthetic style transform a rasterizer should apply alongside a resolved `FontMatch`,
+/// mirroring the `SyntheticItalics`/synthesis flags rasterizers already carry when no
+/// face exactly matches the requested style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FontSynthesis {
+    /// The requested weight is heavier than the matched face's weight; draw faux bold.
+    pub embolden: bool,
+    /// How much heavier the requested weight is than the matched face's, in `FcWeight`
+    /// units. `0` unless `embolden` is set.
+    pub embolden_weight_delta: i32,
+    /// Italic/oblique was requested but only an upright face matched; skew glyphs by
+    /// this many degrees to fake it. `0` when no skew is needed.
+    pub skew_x_degrees: i32,
+}
+
+impl FontSynthesis {
+    /// Skew applied for synthetic italic/oblique, matching common rasterizer conventions.
+    pub const DEFAULT_SKEW_DEGREES: i32 = 14;
+
+    /// Compare what was `requested` against the `matched` face and derive the faux
+    /// styling (if any) a rasterizer needs to apply to bridge the gap.
+    pub fn compute(requested: &FcPattern, matched: &FcPattern) -> Self {
+        let requested_weight = requested.weight as i32;
+        let matched_weight = matched.weight as i32;
+        let embolden = requested_weight > matched_weight;
+        let embolden_weight_delta = if embolden {
+            requested_weight - matched_weight
+        } else {
+            0
+        };
+
+        let wants_italic =
+            requested.italic == PatternMatch::True || requested.oblique == PatternMatch::True;
+        let has_italic =
+            matched.italic == PatternMatch::True || matched.oblique == PatternMatch::True;
+        let skew_x_degrees = if wants_italic && !has_italic {
+            Self::DEFAULT_SKEW_DEGREES
+        } else {
+            0
+        };
+
+        FontSynthesis {
+            embolden,
+            embolden_weight_delta,
+            skew_x_degrees,
+        }
+    }
+
+    /// Whether a rasterizer needs to draw faux bold for this match. Equivalent to `embolden`,
+    /// named to match the `fake_bold`/`fake_italic` vocabulary callers coming from other
+    /// font-matching APIs tend to look for first.
+    pub fn fake_bold(&self) -> bool {
+        self.embolden
+    }
+
+    /// Whether a rasterizer needs to apply a synthetic slant for this match. Equivalent to
+    /// `skew_x_degrees != 0`.
+    pub fn fake_italic(&self) -> bool {
+        self.skew_x_degrees != 0
+    }
+}
+
+/// One resolved style slot in a `FaceSet`: the matched face plus whether it's the real thing
+/// or a degraded substitute the caller should synthesize on top of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaceSetSlot {
+    pub font_match: FontMatch,
+    /// `true` when no face in this slot matched the requested style exactly, so
+    /// `font_match.synthesis` carries faux bold/italic the caller should apply - equivalent to
+    /// `font_match.synthesis.fake_bold() || font_match.synthesis.fake_italic()`, kept as a
+    /// field so callers don't have to know `FontSynthesis`'s shape to branch on it.
+    pub is_synthetic: bool,
+}
+
+impl FaceSetSlot {
+    fn new(font_match: FontMatch) -> Self {
+        let is_synthetic = font_match.synthesis.fake_bold() || font_match.synthesis.fake_italic();
+        FaceSetSlot {
+            font_match,
+            is_synthetic,
+        }
+    }
+}
+
+/// Coordinated regular/bold/italic/bold-italic faces for one family, returned by
+/// `FcFontCache::query_face_set`. A `None` slot means nothing satisfied that style combination
+/// at all, not even a degraded substitute; see `FaceSetSlot::is_synthetic` for the latter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaceSet {
+    pub regular: Option<FaceSetSlot>,
+    pub bold: Option<FaceSetSlot>,
+    pub italic: Option<FaceSetSlot>,
+    pub bold_italic: Option<FaceSetSlot>,
+}
+
+/// Vertical font metrics read from `head.unitsPerEm`, `hhea` (ascender/descender/lineGap),
+/// the OS/2 table's `sCapHeight`/`sxHeight`/`xAvgCharWidth`, and the `post` table's
+/// underline fields (`sCapHeight`/`sxHeight` are present since OS/2 version 2; `0` on older
+/// faces that don't carry them, and on faces missing `hhea`/`post` entirely). Stored on
+/// `FcFontMetadata` and consumed by `FallbackMetricOverrides::compute` and
+/// `FcFontRegistry::metrics`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct FontMetrics {
+    pub units_per_em: u16,
+    pub ascender: i16,
+    pub descender: i16,
+    pub line_gap: i16,
+    pub cap_height: i16,
+    pub x_height: i16,
+    pub underline_position: i16,
+    pub underline_thickness: i16,
+    pub average_advance: i16,
+}
+
+/// Layout-ready metrics for a resolved `FontId`, returned by `FcFontCache::get_metrics_by_id`.
+/// Carries the same `units_per_em`/`ascender`/`descender`/`line_gap`/`cap_height`/`x_height`
+/// fields as `FontMetrics` (read from `head`/`hhea`/OS-2 during the initial scan) plus
+/// `monospace_em_width`, which isn't - that one's only computed the first time a given
+/// `FontId` is requested and memoized from then on. Kept as its own type rather than adding
+/// the field to `FontMetrics` itself, since `FontMetrics` lives inside `FcFontMetadata` and
+/// `FcPattern`, both of which derive `Ord` for use as `BTreeMap` keys - an `Option<f32>`
+/// field would break that.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CachedFontMetrics {
+    pub units_per_em: u16,
+    pub ascender: i16,
+    pub descender: i16,
+    pub line_gap: i16,
+    pub cap_height: i16,
+    pub x_height: i16,
+    /// The shared `hmtx` advance width (in font units), if every glyph with its own `hmtx`
+    /// entry reports the same one - i.e. the face is monospace. `None` for proportional
+    /// fonts, or if the font's bytes/`hhea`/`maxp`/`hmtx` couldn't be read.
+    pub monospace_em_width: Option<f32>,
+}
+
+/// CSS `@font-face`-style adjustment ratios (not percentages — multiply by the requested font
+/// size, or by 100 for the CSS percentage form) that make a fallback font occupy nearly the
+/// same box as the target font it's substituting for, the way Next.js's `next/font/local`
+/// computes them via allsorts for automatic fallback-metric matching.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FallbackMetricOverrides {
+    /// Scales the fallback so its x-height matches the target's.
+    pub size_adjust: f32,
+    pub ascent_override: f32,
+    pub descent_override: f32,
+    pub line_gap_override: f32,
+}
+
+impl FallbackMetricOverrides {
+    /// Computes the overrides that make `fallback` approximate `target`'s box.
+    ///
+    /// `size_adjust` is the ratio of the two fonts' x-height-per-em; each `*_override` then
+    /// expresses the corresponding `target` metric as a fraction of the fallback's em square
+    /// *after* that scaling is applied (`fallback.units_per_em * size_adjust`), so a rasterizer
+    /// that applies `size_adjust` first and then the overrides reproduces `target`'s box almost
+    /// exactly regardless of how different the two faces' native metrics are.
+    ///
+    /// Falls back to `size_adjust: 1.0` (no rescaling) when either side is missing x-height or
+    /// `unitsPerEm` data, and to `0.0` for any override ratio whose scaled em square is zero.
+    pub fn compute(target: &FontMetrics, fallback: &FontMetrics) -> Self {
+        let target_x_height_ratio = if target.units_per_em != 0 {
+            target.x_height as f32 / target.units_per_em as f32
+        } else {
+            0.0
+        };
+        let fallback_x_height_ratio = if fallback.units_per_em != 0 {
+            fallback.x_height as f32 / fallback.units_per_em as f32
+        } else {
+            0.0
+        };
+
+        let size_adjust = if target_x_height_ratio > 0.0 && fallback_x_height_ratio > 0.0 {
+            target_x_height_ratio / fallback_x_height_ratio
+        } else {
+            1.0
+        };
+
+        let scaled_units_per_em = fallback.units_per_em as f32 * size_adjust;
+        let override_ratio = |target_metric: i16| -> f32 {
+            if scaled_units_per_em > 0.0 {
+                target_metric as f32 / scaled_units_per_em
+            } else {
+                0.0
+            }
+        };
+
+        FallbackMetricOverrides {
+            size_adjust,
+            ascent_override: override_ratio(target.ascender),
+            descent_override: override_ratio(target.descender.abs()),
+            line_gap_override: override_ratio(target.line_gap),
+        }
+    }
+}
+
+/// One extended-grapheme-cluster run resolved to a single font by
+/// `FcFontCache::query_for_text_clustered`: every codepoint in `range` (a byte range into the
+/// queried `&str`) renders with `font` rather than being resolved independently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRunSegment {
+    pub range: Range<usize>,
+    pub font: FontId,
+    /// `true` if no font in the pattern's own fallback order covered this cluster and `font`
+    /// was substituted from `last_resort_families`/the builtin tofu face instead - see
+    /// `FcFontCache::with_builtin_last_resort`. Callers that want to tell a "this is the
+    /// font you asked for" run from a "this is a placeholder box" run should check this
+    /// rather than comparing `font` against a known ID.
+    pub is_last_resort: bool,
+}
+
+/// Font match result with UUID (without fallback)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontMatchNoFallback {
+    pub id: FontId,
+    pub unicode_ranges: Vec<UnicodeRange>,
+}
+
+/// Path to a font file
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq)]
+#[repr(C)]
+pub struct FcFontPath {
+    pub path: String,
+    pub font_index: usize,
 }
 
 /// In-memory font data
@@ -722,8 +1813,593 @@ pub enum FontSource<'a> {
     Disk(&'a FcFontPath),
 }
 
+/// A named in-memory font, e.g. one bundled into the binary via `include_bytes!`.
+#[derive(Debug, Clone)]
+pub struct NamedFont {
+    /// Name used to identify this font (also becomes its `FcFont::id`)
+    pub name: String,
+    /// Raw font file bytes (`.ttf`/`.otf`/`.ttc`/`.woff`/`.woff2`)
+    pub bytes: Vec<u8>,
+}
+
+/// Operating system used to pick platform-appropriate font directories and generic
+/// family substitutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatingSystem {
+    MacOS,
+    Linux,
+    Windows,
+    Wasm,
+}
+
+impl OperatingSystem {
+    /// Detects the operating system this binary was compiled for.
+    pub fn current() -> Self {
+        #[cfg(target_os = "macos")]
+        {
+            OperatingSystem::MacOS
+        }
+        #[cfg(target_os = "linux")]
+        {
+            OperatingSystem::Linux
+        }
+        #[cfg(target_os = "windows")]
+        {
+            OperatingSystem::Windows
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+        {
+            OperatingSystem::Wasm
+        }
+    }
+}
+
+/// Looks up the metric-compatible siblings of `family` - fonts designed (or later hinted) to
+/// share the same glyph widths, so swapping within a group doesn't reflow text - in a fixed
+/// per-family priority order. For callers that want to offer a font substitute but need to know whether
+/// it's a strong, layout-preserving equivalent (this table) or merely a weak fallback (generic
+/// aliasing via `expand_font_families`/`default_generic_family_aliases`). Returns `&[]` if
+/// `family` isn't in any group.
+pub fn metric_compatible_alternatives(family: &str) -> &'static [&'static str] {
+    let lower = family.to_lowercase();
+    match lower.as_str() {
+        "arial" => &["Liberation Sans", "Helvetica", "Nimbus Sans", "Helvetica Neue"],
+        "helvetica" | "helvetica neue" => &["Arial", "Liberation Sans", "Nimbus Sans"],
+        "liberation sans" => &["Arial", "Helvetica", "Nimbus Sans"],
+        "nimbus sans" => &["Arial", "Liberation Sans", "Helvetica"],
+
+        "times new roman" => &["Liberation Serif", "Nimbus Roman", "Times"],
+        "times" => &["Times New Roman", "Liberation Serif", "Nimbus Roman"],
+        "liberation serif" => &["Times New Roman", "Nimbus Roman", "Times"],
+        "nimbus roman" => &["Times New Roman", "Liberation Serif", "Times"],
+
+        "courier new" => &["Liberation Mono", "Nimbus Mono", "Courier"],
+        "courier" => &["Courier New", "Liberation Mono", "Nimbus Mono"],
+        "liberation mono" => &["Courier New", "Nimbus Mono", "Courier"],
+        "nimbus mono" => &["Courier New", "Liberation Mono", "Courier"],
+
+        "ms mincho" => &["IPAMincho", "Noto Serif CJK JP"],
+        "ipamincho" => &["MS Mincho", "Noto Serif CJK JP"],
+
+        "ms gothic" => &["IPAGothic", "Noto Sans CJK JP"],
+        "ipagothic" => &["MS Gothic", "Noto Sans CJK JP"],
+
+        "ms pgothic" => &["IPAPGothic", "Noto Sans CJK JP"],
+        "ipapgothic" => &["MS PGothic", "Noto Sans CJK JP"],
+
+        _ => &[],
+    }
+}
+
+/// Expands a CSS-style `font-family` stack, substituting OS-appropriate concrete families
+/// for generic names (`serif`, `sans-serif`, `monospace`, `cursive`, `fantasy`), and metric-
+/// compatible siblings (see `metric_compatible_alternatives`) for specific families that have
+/// them. `extra_aliases` lets callers (in practice, `FcFontRegistry::register_generic_alias`
+/// and fontconfig's own `<alias>`/`<default>` rules) override or extend the built-in generic
+/// table on a per-generic basis; an entry there wins over `default_generic_family_aliases` for
+/// the same name.
+///
+/// Each generic is expanded in place: its concrete substitutes are inserted first, duplicates
+/// against what's already in the stack are dropped, and the generic name itself is kept as the
+/// last entry so a matcher that still sees it (e.g. because none of the substitutes are
+/// installed) can fall back to boolean generic-family matching rather than failing outright.
+/// A specific (non-generic) family is kept first, followed immediately by its metric-compatible
+/// siblings in priority order, so a matcher that tries candidates in order falls through to a
+/// layout-equivalent substitute before ever reaching the generic OS defaults.
+pub fn expand_font_families(
+    families: &[String],
+    os: OperatingSystem,
+    extra_aliases: &[(String, Vec<String>)],
+) -> Vec<String> {
+    let mut expanded: Vec<String> = Vec::with_capacity(families.len());
+
+    for family in families {
+        let lower = family.to_lowercase();
+        let is_generic = matches!(
+            lower.as_str(),
+            "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
+        );
+
+        if !is_generic {
+            if !expanded.iter().any(|f| f.eq_ignore_ascii_case(family)) {
+                expanded.push(family.clone());
+            }
+            for alternative in metric_compatible_alternatives(family) {
+                if !expanded.iter().any(|f| f.eq_ignore_ascii_case(alternative)) {
+                    expanded.push(alternative.to_string());
+                }
+            }
+            continue;
+        }
+
+        let preferred = extra_aliases
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&lower))
+            .map(|(_, substitutes)| substitutes.clone())
+            .unwrap_or_else(|| {
+                default_generic_family_aliases(os, &lower)
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+
+        for concrete in preferred {
+            if !expanded.iter().any(|f| f.eq_ignore_ascii_case(&concrete)) {
+                expanded.push(concrete);
+            }
+        }
+
+        expanded.push(family.clone());
+    }
+
+    expanded
+}
+
+/// Built-in, OS-appropriate concrete families for a generic CSS family name, used by
+/// `expand_font_families` when no caller-supplied `extra_aliases` entry covers `generic`.
+/// Mirrors what a browser's user-agent stylesheet would fall back to on each platform; callers
+/// that want something else should register an override rather than patch this table.
+fn default_generic_family_aliases(os: OperatingSystem, generic: &str) -> &'static [&'static str] {
+    match (os, generic) {
+        (OperatingSystem::MacOS, "serif") => &["Times New Roman", "Georgia", "Times"],
+        (OperatingSystem::MacOS, "sans-serif") => &["Helvetica Neue", "Helvetica", "Arial"],
+        (OperatingSystem::MacOS, "monospace") => &["Menlo", "SF Mono", "Courier"],
+        (OperatingSystem::MacOS, "cursive") => &["Apple Chancery", "Comic Sans MS"],
+        (OperatingSystem::MacOS, "fantasy") => &["Papyrus", "Comic Sans MS"],
+
+        (OperatingSystem::Linux, "serif") => &["DejaVu Serif", "Liberation Serif", "Noto Serif"],
+        (OperatingSystem::Linux, "sans-serif") => {
+            &["DejaVu Sans", "Liberation Sans", "Noto Sans"]
+        }
+        (OperatingSystem::Linux, "monospace") => {
+            &["DejaVu Sans Mono", "Liberation Mono", "Noto Sans Mono"]
+        }
+        (OperatingSystem::Linux, "cursive") => &["Noto Sans", "DejaVu Sans"],
+        (OperatingSystem::Linux, "fantasy") => &["DejaVu Sans", "Noto Sans"],
+
+        (OperatingSystem::Windows, "serif") => &["Times New Roman", "Georgia"],
+        (OperatingSystem::Windows, "sans-serif") => &["Segoe UI", "Arial", "Tahoma"],
+        (OperatingSystem::Windows, "monospace") => &["Consolas", "Courier New"],
+        (OperatingSystem::Windows, "cursive") => &["Comic Sans MS"],
+        (OperatingSystem::Windows, "fantasy") => &["Impact"],
+
+        // "system-ui" and Wasm builds have no sensible concrete default; leave substitution
+        // to `extra_aliases` or the matcher's own boolean generic-family fields.
+        _ => &[],
+    }
+}
+
+/// Broad-coverage family names `FcFontCache::build()` seeds `last_resort_families` with for
+/// the given `OperatingSystem`, so `resolve_char_or_last_resort` has somewhere to look before
+/// falling through to scanning every installed font. Picked per platform for fonts that
+/// commonly bundle CJK, symbol, and emoji coverage - the kind of glyphs a requested CSS stack
+/// is least likely to cover itself.
+fn default_last_resort_families(os: OperatingSystem) -> Vec<String> {
+    match os {
+        OperatingSystem::MacOS => ["PingFang SC", "Apple Color Emoji", "Arial Unicode MS"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        OperatingSystem::Linux => ["Noto Sans CJK SC", "Noto Color Emoji", "Noto Sans Symbols"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        OperatingSystem::Windows => ["Microsoft YaHei", "Segoe UI Symbol", "Segoe UI Emoji"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        // No sensible platform default; callers can still set one via
+        // `with_last_resort_families`.
+        OperatingSystem::Wasm => Vec::new(),
+    }
+}
+
+/// Parses one or more fontconfig-style XML config files (e.g. a `fonts.conf`) into generic
+/// family aliases and `<match target="pattern">` substitution rules, for
+/// `FcFontCache::build_with_config`. Unlike `registry::discover_font_directories_and_aliases`,
+/// which probes the usual system config locations, this takes explicit paths and ignores
+/// `<dir>`/`<include>` - the caller already knows which font directories to scan. Files that
+/// don't exist or fail to parse are skipped rather than aborting the whole load.
+#[cfg(all(feature = "std", feature = "parsing"))]
+fn parse_fontconfig_config(paths: &[PathBuf]) -> (BTreeMap<String, Vec<String>>, Vec<FcMatchRule>) {
+    let mut aliases: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut rules = Vec::new();
+
+    for path in paths {
+        if let Ok(xml) = std::fs::read_to_string(path) {
+            parse_fontconfig_config_xml(&xml, &mut aliases, &mut rules);
+        }
+    }
+
+    rules_dedup_stable(&mut rules);
+    (aliases, rules)
+}
+
+/// Drops exact-duplicate rules (e.g. the same config file listed twice) while keeping the
+/// first occurrence's position, so rule order - and thus which `<edit mode="assign">` wins
+/// last - stays deterministic.
+#[cfg(all(feature = "std", feature = "parsing"))]
+fn rules_dedup_stable(rules: &mut Vec<FcMatchRule>) {
+    let mut seen: Vec<FcMatchRule> = Vec::with_capacity(rules.len());
+    rules.retain(|rule| {
+        if seen.contains(rule) {
+            false
+        } else {
+            seen.push(rule.clone());
+            true
+        }
+    });
+}
+
+/// Tokenizes one fontconfig XML document, collecting `<alias>` (`<family>`/`<prefer>`/
+/// `<accept>`/`<default>`) generic-family aliases and `<match target="pattern">` substitution
+/// rules. `<dir>`/`<include>` are intentionally not handled here, see `parse_fontconfig_config`.
+#[cfg(all(feature = "std", feature = "parsing"))]
+fn parse_fontconfig_config_xml(
+    input: &str,
+    aliases: &mut BTreeMap<String, Vec<String>>,
+    rules: &mut Vec<FcMatchRule>,
+) {
+    use xmlparser::ElementEnd;
+    use xmlparser::Token::*;
+
+    #[derive(PartialEq)]
+    enum Tag {
+        Alias,
+        Prefer,
+        Accept,
+        Default,
+        Match,
+        Test,
+        Edit,
+        Other,
+    }
+
+    let mut stack: Vec<Tag> = Vec::new();
+
+    // <alias> state
+    let mut alias_generic: Option<String> = None;
+    let mut alias_families: Vec<String> = Vec::new();
+
+    // <match> state
+    let mut match_target_is_pattern = true;
+    let mut current_rule = FcMatchRule::default();
+    let mut test_name: Option<String> = None;
+
+    // <edit>/<test> shared state: the attribute name/mode/compare plus any nested <const>/
+    // <string>/<int>/<double>/<bool> leaf text
+    let mut edit_name: Option<String> = None;
+    let mut edit_mode = FcEditMode::Assign;
+    let mut leaf_text: Option<String> = None;
+
+    for token_result in xmlparser::Tokenizer::from(input) {
+        let token = match token_result {
+            Ok(t) => t,
+            Err(_) => return,
+        };
+
+        match token {
+            ElementStart { local, .. } => {
+                let tag = match local.as_str() {
+                    "alias" => Tag::Alias,
+                    "prefer" => Tag::Prefer,
+                    "accept" => Tag::Accept,
+                    "default" => Tag::Default,
+                    "match" => Tag::Match,
+                    "test" => Tag::Test,
+                    "edit" => Tag::Edit,
+                    _ => Tag::Other,
+                };
+                match tag {
+                    Tag::Alias => {
+                        alias_generic = None;
+                        alias_families.clear();
+                    }
+                    Tag::Match => {
+                        match_target_is_pattern = true;
+                        current_rule = FcMatchRule::default();
+                    }
+                    Tag::Test => {
+                        test_name = None;
+                    }
+                    Tag::Edit => {
+                        edit_name = None;
+                        edit_mode = FcEditMode::Assign;
+                    }
+                    _ => {}
+                }
+                leaf_text = None;
+                stack.push(tag);
+            }
+            Attribute { local, value, .. } => match stack.last() {
+                Some(Tag::Match) if local.as_str() == "target" => {
+                    match_target_is_pattern = value.as_str() == "pattern";
+                }
+                Some(Tag::Test) if local.as_str() == "name" => {
+                    test_name = Some(value.as_str().to_lowercase());
+                }
+                Some(Tag::Edit) if local.as_str() == "name" => {
+                    edit_name = Some(value.as_str().to_lowercase());
+                }
+                Some(Tag::Edit) if local.as_str() == "mode" => {
+                    edit_mode = match value.as_str() {
+                        "prepend" | "prepend_first" => FcEditMode::Prepend,
+                        "append" | "append_last" => FcEditMode::Append,
+                        _ => FcEditMode::Assign,
+                    };
+                }
+                _ => {}
+            },
+            Text { text, .. } => {
+                let text = text.as_str().trim();
+                if text.is_empty() {
+                    continue;
+                }
+                // Text sits inside a leaf like `<family>`/`<string>`/`<const>`; attribute it to
+                // the nearest enclosing element this parser tracks.
+                match stack.iter().rev().find(|t| **t != Tag::Other) {
+                    Some(Tag::Prefer) | Some(Tag::Accept) => alias_families.push(text.to_string()),
+                    Some(Tag::Alias) => alias_generic = Some(text.to_lowercase()),
+                    Some(Tag::Default) => {
+                        // A bare top-level <default> names a system-wide fallback, handled by
+                        // the caller the same way `registry::parse_fonts_conf_xml` does; within
+                        // an <alias> it's one more family to add after prefer/accept.
+                        if stack.iter().any(|t| *t == Tag::Alias) {
+                            alias_families.push(text.to_string());
+                        }
+                    }
+                    Some(Tag::Test) | Some(Tag::Edit) => leaf_text = Some(text.to_string()),
+                    _ => {}
+                }
+            }
+            ElementEnd { end, .. } => {
+                let closed = match end {
+                    ElementEnd::Close(_, name) => name.as_str(),
+                    _ => continue,
+                };
+                match closed {
+                    "alias" => {
+                        if let Some(generic) = alias_generic.take() {
+                            if !alias_families.is_empty() {
+                                aliases
+                                    .entry(generic)
+                                    .or_insert_with(Vec::new)
+                                    .extend(alias_families.drain(..));
+                            }
+                        }
+                    }
+                    "test" => {
+                        if let (Some(name), Some(text)) = (test_name.take(), leaf_text.take()) {
+                            if name == "family" {
+                                current_rule.tests.push(FcSubstTest { family: text });
+                            }
+                        }
+                    }
+                    "edit" => {
+                        if let (Some(name), Some(text)) = (edit_name.take(), leaf_text.take()) {
+                            let edit = match name.as_str() {
+                                "weight" => parse_fc_weight(&text).ok().map(|value| FcSubstEdit::Weight {
+                                    mode: edit_mode,
+                                    value,
+                                }),
+                                "slant" => match text.to_lowercase().as_str() {
+                                    "italic" => Some(FcSubstEdit::Slant {
+                                        mode: edit_mode,
+                                        value: FcSlant::Italic,
+                                    }),
+                                    "oblique" => Some(FcSubstEdit::Slant {
+                                        mode: edit_mode,
+                                        value: FcSlant::Oblique,
+                                    }),
+                                    "roman" => Some(FcSubstEdit::Slant {
+                                        mode: edit_mode,
+                                        value: FcSlant::Roman,
+                                    }),
+                                    _ => None,
+                                },
+                                "spacing" => parse_fc_spacing(&text).map(|value| FcSubstEdit::Spacing {
+                                    mode: edit_mode,
+                                    value,
+                                }),
+                                _ => None,
+                            };
+                            if let Some(edit) = edit {
+                                current_rule.edits.push(edit);
+                            }
+                        }
+                    }
+                    "match" => {
+                        if match_target_is_pattern && !current_rule.edits.is_empty() {
+                            rules.push(std::mem::take(&mut current_rule));
+                        }
+                    }
+                    _ => {}
+                }
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A resolved fallback list for one entry of a CSS `font-family` stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssFallbackGroup {
+    /// The (possibly generic) family name this group was resolved from
+    pub css_name: String,
+    /// Faces that satisfy `css_name`, best match first
+    pub fonts: Vec<FontMatch>,
+}
+
+/// Cache key for a resolved `FontFallbackChain`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontChainCacheKey {
+    pub font_families: Vec<String>,
+    pub weight: FcWeight,
+    pub italic: PatternMatch,
+    pub oblique: PatternMatch,
+    /// BCP-47 language tags (e.g. `zh-CN`, `ja`) the chain was resolved for. Empty for
+    /// requests that don't care, so it doesn't collide with a language-aware resolution of
+    /// the same family stack (see `FcFontRegistry::resolve_font_chain_for_languages`).
+    pub languages: Vec<String>,
+}
+
+/// A fully-resolved font fallback chain for a CSS `font-family` stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontFallbackChain {
+    /// One fallback group per entry of the original family stack
+    pub css_fallbacks: Vec<CssFallbackGroup>,
+    /// Faces chosen purely by Unicode coverage, not requested by name
+    pub unicode_fallbacks: Vec<FontMatch>,
+    /// The family stack this chain was resolved from (after generic-family expansion)
+    pub original_stack: Vec<String>,
+}
+
+/// Sweepline decomposition of every cached font's Unicode coverage ranges into sorted
+/// breakpoints and, for each interval between consecutive breakpoints, the `FontId`s that
+/// cover the whole interval - turns "which fonts cover this codepoint?" into a binary search
+/// (`candidates_for`) instead of scanning every font's ranges per codepoint, the way
+/// `query_for_text`'s fallback walk used to. Built by `FcFontCache::coverage_candidates` from
+/// each font's `cmap_coverage` (falling back to the coarser `unicode_ranges` when a face has no
+/// recorded `cmap` coverage), mirroring `FcPattern::contains_char`'s own preference.
+struct CoverageIndex {
+    breakpoints: Vec<u32>,
+    candidates: Vec<Vec<FontId>>,
+}
+
+impl CoverageIndex {
+    fn build(metadata: &BTreeMap<FontId, FcPattern>) -> Self {
+        let mut edges: Vec<u32> = Vec::new();
+        for pattern in metadata.values() {
+            let ranges = if !pattern.cmap_coverage.is_empty() {
+                &pattern.cmap_coverage
+            } else {
+                &pattern.unicode_ranges
+            };
+            for range in ranges {
+                edges.push(range.start);
+                edges.push(range.end.saturating_add(1));
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut candidates: Vec<Vec<FontId>> = vec![Vec::new(); edges.len()];
+        for (id, pattern) in metadata {
+            let ranges = if !pattern.cmap_coverage.is_empty() {
+                &pattern.cmap_coverage
+            } else {
+                &pattern.unicode_ranges
+            };
+            for range in ranges {
+                let start_idx = edges.partition_point(|&e| e < range.start);
+                let end_idx = edges.partition_point(|&e| e <= range.end);
+                for bucket in &mut candidates[start_idx..end_idx] {
+                    bucket.push(*id);
+                }
+            }
+        }
+
+        CoverageIndex {
+            breakpoints: edges,
+            candidates,
+        }
+    }
+
+    fn candidates_for(&self, codepoint: u32) -> &[FontId] {
+        let idx = self.breakpoints.partition_point(|&b| b <= codepoint);
+        if idx == 0 {
+            return &[];
+        }
+        self.candidates
+            .get(idx - 1)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// Precomputed data backing `find_fallbacks`, so it no longer recomputes Unicode coverage for
+/// every installed font (`calculate_unicode_coverage`) nor scans the entire `patterns` map on
+/// every call. `coverage` caches each `FontId`'s total `unicode_ranges` coverage once;
+/// `by_range_start` indexes `FontId`s by where their `unicode_ranges` entries begin so
+/// `candidates_overlapping` can narrow to the fonts that could possibly overlap a requested
+/// range via a `BTreeMap` range scan instead of visiting every font.
+struct FallbackIndex {
+    coverage: BTreeMap<FontId, u64>,
+    by_range_start: BTreeMap<u32, Vec<FontId>>,
+}
+
+impl FallbackIndex {
+    fn build(metadata: &BTreeMap<FontId, FcPattern>) -> Self {
+        let mut coverage = BTreeMap::new();
+        let mut by_range_start: BTreeMap<u32, Vec<FontId>> = BTreeMap::new();
+
+        for (id, pattern) in metadata {
+            coverage.insert(
+                *id,
+                FcFontCache::calculate_unicode_coverage(&pattern.unicode_ranges),
+            );
+            for range in &pattern.unicode_ranges {
+                by_range_start.entry(range.start).or_default().push(*id);
+            }
+        }
+
+        FallbackIndex {
+            coverage,
+            by_range_start,
+        }
+    }
+
+    /// `FontId`s with at least one `unicode_ranges` entry overlapping `query`, found by scanning
+    /// only the range-start buckets up to `query.end` rather than every installed font.
+    fn candidates_overlapping(
+        &self,
+        query: &UnicodeRange,
+        metadata: &BTreeMap<FontId, FcPattern>,
+    ) -> Vec<FontId> {
+        let mut result = Vec::new();
+        for ids in self.by_range_start.range(..=query.end).map(|(_, ids)| ids) {
+            for id in ids {
+                if result.contains(id) {
+                    continue;
+                }
+                if let Some(stored_pattern) = metadata.get(id) {
+                    if stored_pattern
+                        .unicode_ranges
+                        .iter()
+                        .any(|r| r.overlaps(query))
+                    {
+                        result.push(*id);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
 /// Font cache, initialized at startup
-#[derive(Debug, Default, Clone)]
+#[derive(Default)]
 pub struct FcFontCache {
     // Pattern to FontId mapping (query index)
     patterns: BTreeMap<FcPattern, FontId>,
@@ -733,6 +2409,100 @@ pub struct FcFontCache {
     memory_fonts: BTreeMap<FontId, FcFont>,
     // Metadata cache (patterns stored by ID for quick lookup)
     metadata: BTreeMap<FontId, FcPattern>,
+    // Memoized, priority-sorted fallback order per base pattern (see `build_fallback_order`)
+    fallback_order_cache: RefCell<BTreeMap<FcPattern, Vec<FontId>>>,
+    // Lowercase name/family token -> set of FontIds (inverted index for fuzzy name search)
+    token_index: BTreeMap<String, BTreeSet<FontId>>,
+    // FontId -> pre-tokenized lowercase name tokens
+    font_tokens: BTreeMap<FontId, Vec<String>>,
+    /// Ordered family names `resolve_char_or_last_resort` tries, by name, once the requested
+    /// CSS + Unicode chain has no glyph for a codepoint - set by `build()` to a broad-coverage
+    /// default for the current `OperatingSystem`, or overridden via `with_last_resort_families`.
+    last_resort_families: Vec<String>,
+    /// When `true`, `query_for_text_clustered` substitutes `FontId::BUILTIN_LAST_RESORT` for
+    /// any cluster `last_resort_families` still can't cover, instead of dropping it from the
+    /// returned segments - see `with_builtin_last_resort`. Defaults to `false` so existing
+    /// callers keep seeing uncovered clusters omitted rather than a sentinel ID they don't
+    /// know how to render.
+    include_builtin_last_resort: bool,
+    /// Lazily computed `get_metrics_by_id` results, keyed by `FontId` so repeated layout
+    /// calls for the same resolved font don't re-walk `hmtx` for `monospace_em_width` every
+    /// time - mirrors `fallback_order_cache`'s "memoize on first use" shape.
+    metrics_cache: RefCell<BTreeMap<FontId, CachedFontMetrics>>,
+    /// Generic family (serif/sans-serif/monospace/...) -> preferred concrete families, loaded
+    /// from a fontconfig `<alias>` config by `build_with_config`, or on Linux by `build()` from
+    /// `/etc/fonts/fonts.conf`. When empty, `expand_font_families` falls back to
+    /// `default_generic_family_aliases`.
+    generic_aliases: BTreeMap<String, Vec<String>>,
+    /// `<match target="pattern">` substitution rules loaded by `build_with_config` (or on
+    /// Linux by `build()` from `/etc/fonts/fonts.conf`), applied by
+    /// `query`/`query_all`/`query_with_strictness` before matching (fontconfig's
+    /// `FcConfigSubstitute` phase).
+    substitutions: Vec<FcMatchRule>,
+    /// Lazily opened, memory-mapped bytes for on-disk fonts already fetched via
+    /// `get_font_bytes_mmap`, keyed by `FontId` - avoids copying large `.ttc`/`.otf` files
+    /// into an owned `Vec<u8>` on every call the way `get_font_bytes` does. Memory fonts
+    /// never appear here since their bytes are already owned directly by `memory_fonts`.
+    /// Not preserved across `Clone` (a clone starts with an empty cache and re-maps
+    /// lazily on next access) since an `memmap2::Mmap` itself can't be cloned.
+    #[cfg(all(not(target_family = "wasm"), feature = "std"))]
+    mmap_cache: RefCell<BTreeMap<FontId, memmap2::Mmap>>,
+    /// Sweepline decomposition of every cached font's Unicode coverage into sorted breakpoints
+    /// plus the covering `FontId`s for each interval between them - see `CoverageIndex`. Built
+    /// lazily by `coverage_candidates` on first use and invalidated (reset to `None`) whenever
+    /// `with_memory_fonts`/`with_memory_font_with_id` add a font, since a stale index could
+    /// miss the new font's coverage entirely rather than just being slower to catch up.
+    coverage_index: RefCell<Option<CoverageIndex>>,
+    /// Memoized `(pattern, codepoint) -> resolved FontId` results for `query_for_text`'s
+    /// fallback walk, so repeated codepoints (e.g. spaces, common letters) in the same query
+    /// don't re-walk `build_fallback_order`'s candidates every time they recur.
+    char_resolution_cache: RefCell<BTreeMap<(FcPattern, u32), Option<FontId>>>,
+    /// Precomputed coverage/range-start index consumed by `find_fallbacks` - see
+    /// `FallbackIndex`. Built lazily on first use and invalidated (reset to `None`) on the same
+    /// font-mutating calls as `coverage_index`.
+    fallback_index: RefCell<Option<FallbackIndex>>,
+}
+
+impl core::fmt::Debug for FcFontCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FcFontCache")
+            .field("patterns", &self.patterns)
+            .field("disk_fonts", &self.disk_fonts)
+            .field("memory_fonts", &self.memory_fonts)
+            .field("metadata", &self.metadata)
+            .field("last_resort_families", &self.last_resort_families)
+            .field("include_builtin_last_resort", &self.include_builtin_last_resort)
+            .field("generic_aliases", &self.generic_aliases)
+            .field("substitutions", &self.substitutions)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clone for FcFontCache {
+    /// Clones every field except the memoization/mmap caches, which start out empty again
+    /// (they repopulate lazily on next access) rather than being deep-copied - `memmap2::Mmap`
+    /// specifically can't be cloned at all, since it's tied to one open file mapping.
+    fn clone(&self) -> Self {
+        Self {
+            patterns: self.patterns.clone(),
+            disk_fonts: self.disk_fonts.clone(),
+            memory_fonts: self.memory_fonts.clone(),
+            metadata: self.metadata.clone(),
+            fallback_order_cache: RefCell::new(BTreeMap::new()),
+            token_index: self.token_index.clone(),
+            font_tokens: self.font_tokens.clone(),
+            last_resort_families: self.last_resort_families.clone(),
+            include_builtin_last_resort: self.include_builtin_last_resort,
+            metrics_cache: RefCell::new(BTreeMap::new()),
+            generic_aliases: self.generic_aliases.clone(),
+            substitutions: self.substitutions.clone(),
+            #[cfg(all(not(target_family = "wasm"), feature = "std"))]
+            mmap_cache: RefCell::new(BTreeMap::new()),
+            coverage_index: RefCell::new(None),
+            char_resolution_cache: RefCell::new(BTreeMap::new()),
+            fallback_index: RefCell::new(None),
+        }
+    }
 }
 
 impl FcFontCache {
@@ -744,6 +2514,38 @@ impl FcFontCache {
             self.metadata.insert(id, pattern);
             self.memory_fonts.insert(id, font);
         }
+        *self.coverage_index.borrow_mut() = None;
+        self.char_resolution_cache.borrow_mut().clear();
+        *self.fallback_index.borrow_mut() = None;
+        self
+    }
+
+    /// Overrides the family names `resolve_char_or_last_resort` tries, by name, before
+    /// falling through to scanning every installed font's charset. Replaces (rather than
+    /// extends) whatever `build()` defaulted this to for the current `OperatingSystem`.
+    pub fn with_last_resort_families(&mut self, families: Vec<String>) -> &mut Self {
+        self.last_resort_families = families;
+        self
+    }
+
+    /// Pins one generic family (`"serif"`, `"sans-serif"`, `"monospace"`, `"cursive"`,
+    /// `"fantasy"` or `"system-ui"`) to an explicit, ordered list of concrete family names,
+    /// the same role a fontconfig `<alias>` block plays for `build_with_config`. Overrides
+    /// (rather than appends to) whatever `generic_aliases`/`default_generic_family_aliases`
+    /// would otherwise resolve `generic` to, for every subsequent `query`/`query_all` call.
+    pub fn with_generic_family(&mut self, generic: &str, families: Vec<String>) -> &mut Self {
+        self.generic_aliases
+            .insert(generic.to_lowercase(), families);
+        self
+    }
+
+    /// Opts into the bundled "tofu"/notdef placeholder as the very last fallback: once
+    /// `last_resort_families` has also failed to cover a grapheme cluster,
+    /// `query_for_text_clustered` emits `FontId::BUILTIN_LAST_RESORT` (tagged
+    /// `TextRunSegment::is_last_resort`) instead of dropping the cluster, so text always
+    /// renders *something* rather than vanishing mid-run. Off by default.
+    pub fn with_builtin_last_resort(&mut self, enabled: bool) -> &mut Self {
+        self.include_builtin_last_resort = enabled;
         self
     }
 
@@ -757,6 +2559,9 @@ impl FcFontCache {
         self.patterns.insert(pattern.clone(), id);
         self.metadata.insert(id, pattern);
         self.memory_fonts.insert(id, font);
+        *self.coverage_index.borrow_mut() = None;
+        self.char_resolution_cache.borrow_mut().clear();
+        *self.fallback_index.borrow_mut() = None;
         self
     }
 
@@ -770,21 +2575,137 @@ impl FcFontCache {
         if let Some(path) = self.disk_fonts.get(id) {
             return Some(FontSource::Disk(path));
         }
-        None
-    }
+        None
+    }
+
+    /// Get metadata directly from an ID
+    pub fn get_metadata_by_id(&self, id: &FontId) -> Option<&FcPattern> {
+        self.metadata.get(id)
+    }
+
+    /// The face index `id` was parsed from within its font file - `0` for a standalone
+    /// `.ttf`/`.otf`, or the position of the matched face inside a `.ttc`/`.otc` collection
+    /// (see `FcFontPath::font_index`/`FcFont::font_index`, both set per-face by `FcParseFont`).
+    /// Saves callers a `match get_font_by_id(id) { ... }` when all they need is the index to
+    /// pass to their own table provider/rasterizer.
+    pub fn face_index_for_id(&self, id: &FontId) -> Option<usize> {
+        match self.get_font_by_id(id)? {
+            FontSource::Memory(font) => Some(font.font_index),
+            FontSource::Disk(path) => Some(path.font_index),
+        }
+    }
+
+    /// Get font bytes (either from disk or memory)
+    #[cfg(feature = "std")]
+    pub fn get_font_bytes(&self, id: &FontId) -> Option<Vec<u8>> {
+        match self.get_font_by_id(id)? {
+            FontSource::Memory(font) => Some(font.bytes.clone()),
+            FontSource::Disk(path) => std::fs::read(&path.path).ok(),
+        }
+    }
+
+    /// Borrowed font bytes for `id`, backed by a lazily-opened, cached `memmap2::Mmap` for
+    /// disk fonts rather than a fresh `std::fs::read` copy every call - large `.ttc`/`.otf`
+    /// files stay paged in by the OS instead of being fully copied into owned `Vec<u8>`s.
+    /// Memory fonts are returned directly from `memory_fonts` since they're already owned
+    /// in RAM. The returned slice is valid for as long as `self` is (both `disk_fonts`/
+    /// `memory_fonts` and `mmap_cache` are insert-only once a cache is built), so callers
+    /// don't need to re-fetch it on every access the way `get_font_bytes` requires.
+    #[cfg(all(not(target_family = "wasm"), feature = "std"))]
+    pub fn get_font_bytes_mmap(&self, id: &FontId) -> Option<&[u8]> {
+        if let Some(font) = self.memory_fonts.get(id) {
+            return Some(font.bytes.as_slice());
+        }
+
+        if let Some(mmap) = self.mmap_cache.borrow().get(id) {
+            let ptr = mmap.as_ptr();
+            let len = mmap.len();
+            // SAFETY: `mmap` lives in `self.mmap_cache`, which is insert-only - the mapping
+            // stays open (and this pointer valid) for as long as `&self` does.
+            return Some(unsafe { core::slice::from_raw_parts(ptr, len) });
+        }
+
+        let path = self.disk_fonts.get(id)?;
+        let file = std::fs::File::open(&path.path).ok()?;
+        let mmap = unsafe { memmap2::Mmap::map(&file).ok()? };
+        let ptr = mmap.as_ptr();
+        let len = mmap.len();
+        self.mmap_cache.borrow_mut().insert(*id, mmap);
+        // SAFETY: see above - `mmap` now lives in `self.mmap_cache`.
+        Some(unsafe { core::slice::from_raw_parts(ptr, len) })
+    }
+
+    /// Layout metrics for `id` - the vertical metrics `get_metadata_by_id` already carries on
+    /// the matched `FcPattern`, plus `monospace_em_width` detected (and memoized) on first
+    /// request. A one-stop call for a layout/line-wrapping engine that would otherwise have to
+    /// fetch the pattern for vertical metrics and re-parse `hmtx` itself for the horizontal
+    /// ones. `None` if `id` isn't known to this cache.
+    pub fn get_metrics_by_id(&self, id: &FontId) -> Option<CachedFontMetrics> {
+        if let Some(cached) = self.metrics_cache.borrow().get(id) {
+            return Some(*cached);
+        }
+
+        let base = self.metadata.get(id)?.metadata.metrics;
+        let result = CachedFontMetrics {
+            units_per_em: base.units_per_em,
+            ascender: base.ascender,
+            descender: base.descender,
+            line_gap: base.line_gap,
+            cap_height: base.cap_height,
+            x_height: base.x_height,
+            monospace_em_width: self.detect_monospace_em_width(id),
+        };
+
+        self.metrics_cache.borrow_mut().insert(*id, result);
+        Some(result)
+    }
+
+    /// Reads `hhea`/`maxp`/`hmtx` straight from `id`'s font bytes and checks whether every
+    /// glyph with its own `hmtx` entry reports the same advance width - the same check
+    /// `FcParseFont` runs once at scan time to classify `FcSpacing::Mono`, just run again here
+    /// to recover the actual shared width rather than only the boolean. Not run at scan time
+    /// itself since most callers never need it.
+    #[cfg(all(feature = "std", feature = "parsing"))]
+    fn detect_monospace_em_width(&self, id: &FontId) -> Option<f32> {
+        use allsorts_subset_browser::font_data::FontData;
+
+        let font_index = match self.get_font_by_id(id)? {
+            FontSource::Memory(font) => font.font_index,
+            FontSource::Disk(path) => path.font_index,
+        };
+        let bytes = self.get_font_bytes(id)?;
+        let scope = ReadScope::new(&bytes);
+        let font_file = scope.read::<FontData<'_>>().ok()?;
+        let provider = font_file.table_provider(font_index).ok()?;
+
+        let hhea_data = provider.table_data(tag::HHEA).ok()??;
+        let hhea_table = ReadScope::new(&hhea_data).read::<HheaTable>().ok()?;
+        let maxp_data = provider.table_data(tag::MAXP).ok()??;
+        let maxp_table = ReadScope::new(&maxp_data).read::<MaxpTable>().ok()?;
+        let hmtx_data = provider.table_data(tag::HMTX).ok()??;
+        let hmtx_table = ReadScope::new(&hmtx_data)
+            .read_dep::<HmtxTable<'_>>((
+                usize::from(maxp_table.num_glyphs),
+                usize::from(hhea_table.num_h_metrics),
+            ))
+            .ok()?;
+
+        let mut shared_advance = None;
+        for i in 0..hhea_table.num_h_metrics as usize {
+            let advance = hmtx_table.h_metrics.read_item(i).ok()?.advance_width;
+            match shared_advance {
+                None => shared_advance = Some(advance),
+                Some(expected) if expected != advance => return None,
+                Some(_) => {}
+            }
+        }
 
-    /// Get metadata directly from an ID
-    pub fn get_metadata_by_id(&self, id: &FontId) -> Option<&FcPattern> {
-        self.metadata.get(id)
+        shared_advance.map(|width| width as f32)
     }
 
-    /// Get font bytes (either from disk or memory)
-    #[cfg(feature = "std")]
-    pub fn get_font_bytes(&self, id: &FontId) -> Option<Vec<u8>> {
-        match self.get_font_by_id(id)? {
-            FontSource::Memory(font) => Some(font.bytes.clone()),
-            FontSource::Disk(path) => std::fs::read(&path.path).ok(),
-        }
+    #[cfg(not(all(feature = "std", feature = "parsing")))]
+    fn detect_monospace_em_width(&self, _id: &FontId) -> Option<f32> {
+        None
     }
 
     /// Builds a new font cache
@@ -800,13 +2721,15 @@ impl FcFontCache {
 
         #[cfg(target_os = "linux")]
         {
-            if let Some(font_entries) = FcScanDirectories() {
+            if let Some((font_entries, generic_aliases, substitutions)) = FcScanDirectories() {
                 for (pattern, path) in font_entries {
                     let id = FontId::new();
                     cache.patterns.insert(pattern.clone(), id);
                     cache.metadata.insert(id, pattern);
                     cache.disk_fonts.insert(id, path);
                 }
+                cache.generic_aliases = generic_aliases;
+                cache.substitutions = substitutions;
             }
         }
 
@@ -847,9 +2770,79 @@ impl FcFontCache {
             }
         }
 
+        cache.last_resort_families = default_last_resort_families(OperatingSystem::current());
+
+        cache
+    }
+
+    /// Like `build()`, but additionally loads one or more fontconfig-style XML config files
+    /// (e.g. a `fonts.conf`) for `<alias>` generic-family aliases and `<match target="pattern">`
+    /// substitution rules, so generic expansion and style defaults can be customized without
+    /// recompiling - see `parse_fontconfig_config`. Paths that don't exist or fail to parse are
+    /// skipped rather than aborting the scan.
+    #[cfg(all(feature = "std", feature = "parsing"))]
+    pub fn build_with_config(paths: &[PathBuf]) -> Self {
+        let mut cache = Self::build();
+        let (generic_aliases, substitutions) = parse_fontconfig_config(paths);
+        cache.generic_aliases = generic_aliases;
+        cache.substitutions = substitutions;
         cache
     }
 
+    /// Expands generic CSS family names (`serif`, `sans-serif`, `monospace`, ...) in `families`
+    /// into concrete families for `os`, preferring `<alias>` entries loaded by
+    /// `build_with_config` over `expand_font_families`'s built-in defaults. A thin wrapper
+    /// around the free `expand_font_families` function that supplies `self.generic_aliases`,
+    /// mirroring `FcFontRegistry::generic_alias_overrides`.
+    pub fn expand_families(&self, families: &[String], os: OperatingSystem) -> Vec<String> {
+        let extra_aliases: Vec<(String, Vec<String>)> = self
+            .generic_aliases
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        expand_font_families(families, os, &extra_aliases)
+    }
+
+    /// Resolves `c` against an already-resolved `chain`: first its named `css_fallbacks`,
+    /// then its `unicode_fallbacks`, then `last_resort_families` by name, and finally any
+    /// installed font at all whose charset covers `c` - only `None` if literally no font in
+    /// the cache does. Unlike plugging straight into `chain`'s own fields, this guarantees a
+    /// result as long as the cache has any font covering the codepoint, the way GUI toolkits
+    /// keep a "just in case" font behind the user's requested stack instead of tofu-ing out.
+    pub fn resolve_char_or_last_resort(&self, chain: &FontFallbackChain, c: char) -> Option<FontId> {
+        for group in &chain.css_fallbacks {
+            for font_match in &group.fonts {
+                if self.metadata.get(&font_match.id).map_or(false, |meta| meta.contains_char(c)) {
+                    return Some(font_match.id);
+                }
+            }
+        }
+
+        for font_match in &chain.unicode_fallbacks {
+            if self.metadata.get(&font_match.id).map_or(false, |meta| meta.contains_char(c)) {
+                return Some(font_match.id);
+            }
+        }
+
+        for family in &self.last_resort_families {
+            let found = self.patterns.iter().find(|(pattern, _)| {
+                pattern
+                    .family
+                    .as_deref()
+                    .map_or(false, |f| f.eq_ignore_ascii_case(family))
+                    && pattern.contains_char(c)
+            });
+            if let Some((_, id)) = found {
+                return Some(*id);
+            }
+        }
+
+        self.patterns
+            .iter()
+            .find(|(pattern, _)| pattern.contains_char(c))
+            .map(|(_, id)| *id)
+    }
+
     /// Returns the list of fonts and font patterns
     pub fn list(&self) -> Vec<(&FcPattern, FontId)> {
         self.patterns
@@ -858,23 +2851,214 @@ impl FcFontCache {
             .collect()
     }
 
+    /// Every cached font whose precomputed `script_coverage` (see `extract_script_coverage`)
+    /// contains `tag` (e.g. `*b"arab"`, `*b"hani"`) - lets a shaping pipeline ask "which fonts
+    /// can shape this script?" directly instead of probing individual codepoints via
+    /// `resolve_char_or_last_resort`/`fonts_covering`. Patterns that weren't scanned from a
+    /// real font (no recorded coverage) are never included.
+    pub fn fonts_for_script(&self, tag: [u8; 4]) -> Vec<FontId> {
+        self.patterns
+            .iter()
+            .filter(|(pattern, _)| pattern.script_coverage.contains(&tag))
+            .map(|(_, id)| *id)
+            .collect()
+    }
+
+    /// Applies `self.substitutions` to `pattern` before matching (fontconfig's
+    /// `FcConfigSubstitute` phase), recording each applied rule in `trace`. A no-op clone when
+    /// `substitutions` is empty.
+    fn substituted_pattern(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> FcPattern {
+        let mut result = pattern.clone();
+        for rule in &self.substitutions {
+            if rule.matches(&result) {
+                rule.apply(&mut result);
+                trace.push(TraceMsg {
+                    level: TraceLevel::Debug,
+                    path: result
+                        .name
+                        .as_ref()
+                        .or(result.family.as_ref())
+                        .map_or_else(|| "<unknown>".to_string(), Clone::clone),
+                    reason: MatchReason::Substituted {
+                        rule: rule.describe(),
+                    },
+                });
+            }
+        }
+        result
+    }
+
+    /// Resolves `pattern.fullname` (e.g. `"Arial Bold"`, `"Fira Code Retina"`) by trying the
+    /// whole string as a family name first, then progressively stripping trailing words and
+    /// reinterpreting each stripped word as a weight (`parse_fc_weight`) or slant (`italic`/
+    /// `oblique`) token that biases the remaining query, same as a caller would have set those
+    /// fields directly. The earliest (longest) family prefix that yields a match wins, so
+    /// "Arial Bold" prefers an actual "Arial Bold" family over falling back to "Arial" biased
+    /// toward `FcWeight::Bold`. Returns `None` if no prefix - down to the first word alone -
+    /// matches anything.
+    fn query_fullname(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Option<FontMatch> {
+        let fullname = pattern.fullname.as_ref()?;
+        let words: Vec<&str> = fullname.split_whitespace().collect();
+        if words.is_empty() {
+            return None;
+        }
+
+        for split in (1..=words.len()).rev() {
+            let family_candidate = words[..split].join(" ");
+            let style_tokens = &words[split..];
+
+            let mut candidate = pattern.clone();
+            candidate.fullname = None;
+            candidate.name = Some(family_candidate.clone());
+            candidate.family = Some(family_candidate.clone());
+
+            for token in style_tokens {
+                if let Ok(weight) = parse_fc_weight(token) {
+                    candidate.weight = weight;
+                }
+                match token.to_ascii_lowercase().as_str() {
+                    "italic" => candidate.italic = PatternMatch::True,
+                    "oblique" => candidate.oblique = PatternMatch::True,
+                    _ => {}
+                }
+            }
+
+            if let Some(found) = self.query(&candidate, trace) {
+                trace.push(TraceMsg {
+                    level: TraceLevel::Debug,
+                    path: family_candidate.clone(),
+                    reason: MatchReason::FullNameResolved {
+                        requested: fullname.clone(),
+                        matched_family: family_candidate,
+                        style_tokens: style_tokens.iter().map(|s| s.to_string()).collect(),
+                    },
+                });
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
+    /// If `pattern.family` is a CSS/fontconfig generic keyword (`serif`, `sans-serif`,
+    /// `monospace`, `cursive`, `fantasy`, `system-ui`), resolves it the way `fc-match
+    /// monospace` would: tries each of `expand_families`'s concrete substitutes (preferring
+    /// `<alias>` entries loaded by `build_with_config` over the OS-default table) as a full
+    /// `query` in turn, then - if none of those are installed either - falls back to a query
+    /// with the family requirement dropped but the generic's own semantics folded onto the
+    /// pattern instead (e.g. `monospace` implies `monospace == PatternMatch::True`). Returns
+    /// `None` (letting the caller run its normal match) if `pattern.family` isn't a recognized
+    /// generic keyword, or if even the semantic fallback finds nothing.
+    fn query_generic_family(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Option<FontMatch> {
+        let generic = pattern.family.as_deref()?.to_lowercase();
+        if !matches!(
+            generic.as_str(),
+            "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
+        ) {
+            return None;
+        }
+
+        let substitutes = self.expand_families(&[generic.clone()], OperatingSystem::current());
+
+        for concrete in &substitutes {
+            if concrete.eq_ignore_ascii_case(&generic) {
+                continue;
+            }
+
+            let mut candidate = pattern.clone();
+            candidate.family = Some(concrete.clone());
+            if let Some(found) = self.query(&candidate, trace) {
+                return Some(found);
+            }
+        }
+
+        let mut candidate = pattern.clone();
+        candidate.family = None;
+        match generic.as_str() {
+            "monospace" => candidate.monospace = PatternMatch::True,
+            "serif" => candidate.serif = PatternMatch::True,
+            "sans-serif" => candidate.serif = PatternMatch::False,
+            _ => {}
+        }
+        self.query(&candidate, trace)
+    }
+
+    /// Expands `pattern.family` through an `<alias>` rule loaded into `self.generic_aliases`
+    /// (by `build_with_config`, or `build()` itself reading `/etc/fonts/fonts.conf`) before
+    /// falling through to ordinary matching - e.g. a `<alias><family>Helvetica</family>
+    /// <accept><family>Arial</family></accept></alias>` rule makes a request for "Helvetica"
+    /// resolve to "Arial". Tries each declared substitute family in order (the document order
+    /// in which `parse_fontconfig_config_xml` recorded `<prefer>`/`<accept>`/`<default>`), then
+    /// leaves the original family in place for the caller's normal match if none of them hit -
+    /// `query_generic_family` already covers the six CSS generic keywords, so this only fires
+    /// for an alias naming a specific family like "Helvetica" rather than "sans-serif".
+    fn query_family_alias(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Option<FontMatch> {
+        let requested = pattern.family.as_deref()?.to_lowercase();
+        let substitutes = self.generic_aliases.get(&requested)?;
+
+        for concrete in substitutes {
+            if concrete.eq_ignore_ascii_case(&requested) {
+                continue;
+            }
+
+            let mut candidate = pattern.clone();
+            candidate.family = Some(concrete.clone());
+            if let Some(found) = self.query(&candidate, trace) {
+                trace.push(TraceMsg {
+                    level: TraceLevel::Debug,
+                    path: concrete.clone(),
+                    reason: MatchReason::Substituted {
+                        rule: format!("<alias> {} -> {}", requested, concrete),
+                    },
+                });
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
     /// Queries a font from the in-memory cache, returns the first found font (early return)
     pub fn query(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Option<FontMatch> {
+        if pattern.fullname.is_some() {
+            return self.query_fullname(pattern, trace);
+        }
+
+        if let Some(found) = self.query_generic_family(pattern, trace) {
+            return Some(found);
+        }
+
+        if let Some(found) = self.query_family_alias(pattern, trace) {
+            return Some(found);
+        }
+
+        if pattern.exact_style {
+            return self.query_with_strictness(pattern, MatchStrictness::ExactStyle, trace);
+        }
+
+        let substituted = self.substituted_pattern(pattern, trace);
+        let pattern = &substituted;
         let mut matches = Vec::new();
 
         for (stored_pattern, id) in &self.patterns {
             if Self::query_matches_internal(stored_pattern, pattern, trace) {
                 let metadata = self.metadata.get(id).unwrap_or(stored_pattern);
+                let language_score = Self::calculate_language_score(pattern, metadata);
                 let coverage = Self::calculate_unicode_coverage(&metadata.unicode_ranges);
                 let style_score = Self::calculate_style_score(pattern, metadata);
-                matches.push((*id, coverage, style_score, metadata.clone()));
+                matches.push((*id, language_score, coverage, style_score, metadata.clone()));
             }
         }
 
-        // Sort by style score (lowest first), then by unicode coverage (highest first)
-        matches.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| b.1.cmp(&a.1)));
+        // Sort by language score (highest first), then style score (lowest first),
+        // then by unicode coverage (highest first)
+        matches.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.3.cmp(&b.3))
+                .then_with(|| b.2.cmp(&a.2))
+        });
 
-        matches.first().map(|(id, _, _, metadata)| {
+        matches.first().map(|(id, _, _, _, metadata)| {
             // Find fallbacks for this font
             let fallbacks = self.find_fallbacks(metadata, trace);
 
@@ -882,38 +3066,474 @@ impl FcFontCache {
                 id: *id,
                 unicode_ranges: metadata.unicode_ranges.clone(),
                 fallbacks,
+                synthesis: FontSynthesis::compute(pattern, metadata),
+                instantiated_weight: Self::instantiated_weight_for(pattern, metadata),
+            }
+        })
+    }
+
+    /// Queries a font, requiring `weight`, `stretch`, `italic` and `oblique` to match the
+    /// stored face exactly. Returns `None` rather than substituting a synthesized/approximate
+    /// style, unlike `query`.
+    pub fn query_exact(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Option<FontMatch> {
+        self.query_with_strictness(pattern, MatchStrictness::ExactStyle, trace)
+    }
+
+    /// Queries a font with an explicit `MatchStrictness`, see `query` and `query_exact`.
+    pub fn query_with_strictness(
+        &self,
+        pattern: &FcPattern,
+        strictness: MatchStrictness,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Option<FontMatch> {
+        if strictness == MatchStrictness::BestMatch && !pattern.exact_style {
+            return self.query(pattern, trace);
+        }
+
+        let substituted = self.substituted_pattern(pattern, trace);
+        let pattern = &substituted;
+        let mut matches = Vec::new();
+
+        for (stored_pattern, id) in &self.patterns {
+            if !Self::query_matches_internal(stored_pattern, pattern, trace) {
+                continue;
+            }
+
+            let metadata = self.metadata.get(id).unwrap_or(stored_pattern);
+
+            if !Self::exact_style_matches(pattern, metadata, trace) {
+                continue;
+            }
+
+            let coverage = Self::calculate_unicode_coverage(&metadata.unicode_ranges);
+            matches.push((*id, coverage, metadata.clone()));
+        }
+
+        matches.sort_by(|a, b| b.1.cmp(&a.1));
+
+        matches.first().map(|(id, _, metadata)| {
+            let fallbacks = self.find_fallbacks(metadata, trace);
+            FontMatch {
+                id: *id,
+                unicode_ranges: metadata.unicode_ranges.clone(),
+                fallbacks,
+                synthesis: FontSynthesis::compute(pattern, metadata),
+                instantiated_weight: Self::instantiated_weight_for(pattern, metadata),
             }
         })
     }
 
+    /// Resolves one style slot for `query_face_set`: query `base` with `bold`/`italic` set as
+    /// requested, first constrained to `preferred_family` (so e.g. the bold face comes from the
+    /// same family that won the regular match) and, if that yields nothing, again without the
+    /// family constraint so the style combination still resolves to the overall best match.
+    fn query_face_set_slot(
+        &self,
+        base: &FcPattern,
+        preferred_family: Option<&str>,
+        bold: PatternMatch,
+        italic: PatternMatch,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Option<FaceSetSlot> {
+        if let Some(family) = preferred_family {
+            let mut attempt = base.clone();
+            attempt.family = Some(family.to_string());
+            attempt.bold = bold;
+            attempt.italic = italic;
+            if let Some(m) = self.query(&attempt, trace) {
+                return Some(FaceSetSlot::new(m));
+            }
+        }
+
+        let mut attempt = base.clone();
+        attempt.bold = bold;
+        attempt.italic = italic;
+        self.query(&attempt, trace).map(FaceSetSlot::new)
+    }
+
+    /// Resolves coordinated regular/bold/italic/bold-italic faces for one family in a single
+    /// call, the way terminal/GUI configs that only let a user pick one family still expect all
+    /// four style slots to "just work". The regular match is found first via ordinary `query`,
+    /// then the other three slots are resolved preferring that same family - so the bold face is
+    /// the bold of the family that won the regular match rather than an unrelated font that
+    /// merely scored well on the bold query - falling back to the best style match overall if
+    /// the family has no face in that style. Each slot reports via `FaceSetSlot::is_synthetic`
+    /// whether the caller needs to apply faux bold/oblique on top of what was found.
+    pub fn query_face_set(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> FaceSet {
+        let regular = self.query(pattern, trace);
+
+        let preferred_family = regular
+            .as_ref()
+            .and_then(|m| self.get_metadata_by_id(&m.id))
+            .and_then(|p| p.family.as_deref())
+            .or(pattern.family.as_deref())
+            .map(|s| s.to_string());
+
+        let bold = self.query_face_set_slot(
+            pattern,
+            preferred_family.as_deref(),
+            PatternMatch::True,
+            PatternMatch::False,
+            trace,
+        );
+        let italic = self.query_face_set_slot(
+            pattern,
+            preferred_family.as_deref(),
+            PatternMatch::False,
+            PatternMatch::True,
+            trace,
+        );
+        let bold_italic = self.query_face_set_slot(
+            pattern,
+            preferred_family.as_deref(),
+            PatternMatch::True,
+            PatternMatch::True,
+            trace,
+        );
+
+        FaceSet {
+            regular: regular.map(FaceSetSlot::new),
+            bold,
+            italic,
+            bold_italic,
+        }
+    }
+
+    /// Like `query_generic_family`, but for `query_all`: tries each concrete substitute in turn
+    /// and returns the first one with any matches at all, falling back to the generic's own
+    /// semantics (e.g. `monospace == PatternMatch::True`) with no family requirement if none of
+    /// the concrete substitutes are installed. `None` if `pattern.family` isn't a recognized
+    /// generic keyword.
+    fn query_all_generic_family(
+        &self,
+        pattern: &FcPattern,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Option<Vec<FontMatch>> {
+        let generic = pattern.family.as_deref()?.to_lowercase();
+        if !matches!(
+            generic.as_str(),
+            "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
+        ) {
+            return None;
+        }
+
+        let substitutes = self.expand_families(&[generic.clone()], OperatingSystem::current());
+
+        for concrete in &substitutes {
+            if concrete.eq_ignore_ascii_case(&generic) {
+                continue;
+            }
+
+            let mut candidate = pattern.clone();
+            candidate.family = Some(concrete.clone());
+            let found = self.query_all(&candidate, trace);
+            if !found.is_empty() {
+                return Some(found);
+            }
+        }
+
+        let mut candidate = pattern.clone();
+        candidate.family = None;
+        match generic.as_str() {
+            "monospace" => candidate.monospace = PatternMatch::True,
+            "serif" => candidate.serif = PatternMatch::True,
+            "sans-serif" => candidate.serif = PatternMatch::False,
+            _ => {}
+        }
+        Some(self.query_all(&candidate, trace))
+    }
+
     /// Queries all fonts matching a pattern
     pub fn query_all(&self, pattern: &FcPattern, trace: &mut Vec<TraceMsg>) -> Vec<FontMatch> {
+        if let Some(found) = self.query_all_generic_family(pattern, trace) {
+            return found;
+        }
+
+        let substituted = self.substituted_pattern(pattern, trace);
+        let pattern = &substituted;
+        let mut matches = Vec::new();
+
+        for (stored_pattern, id) in &self.patterns {
+            if Self::query_matches_internal(stored_pattern, pattern, trace) {
+                let metadata = self.metadata.get(id).unwrap_or(stored_pattern);
+
+                if pattern.exact_style && !Self::exact_style_matches(pattern, metadata, trace) {
+                    continue;
+                }
+
+                let language_score = Self::calculate_language_score(pattern, metadata);
+                let coverage = Self::calculate_unicode_coverage(&metadata.unicode_ranges);
+                let style_score = Self::calculate_style_score(pattern, metadata);
+                matches.push((*id, language_score, coverage, style_score, metadata.clone()));
+            }
+        }
+
+        // Sort by language score (highest first), then style score (lowest first),
+        // then by unicode coverage (highest first)
+        matches.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.3.cmp(&b.3))
+                .then_with(|| b.2.cmp(&a.2))
+        });
+
+        matches
+            .into_iter()
+            .map(|(id, _, _, _, metadata)| {
+                let fallbacks = self.find_fallbacks(&metadata, trace);
+
+                FontMatch {
+                    id,
+                    unicode_ranges: metadata.unicode_ranges.clone(),
+                    synthesis: FontSynthesis::compute(pattern, &metadata),
+                    instantiated_weight: Self::instantiated_weight_for(pattern, &metadata),
+                    fallbacks,
+                }
+            })
+            .collect()
+    }
+
+    /// Like `query_all`, but returns every matching candidate's `MatchScore` alongside it
+    /// instead of discarding the scoring data, mirroring fontconfig's `FcFontSort` (which
+    /// returns a whole ranked `FcFontSet` rather than `FcFontMatch`'s single winner).
+    ///
+    /// When `trim` is set, a candidate is dropped if none of its `unicode_ranges` add coverage
+    /// beyond what every higher-ranked candidate already listed covers - candidates with no
+    /// recorded `unicode_ranges` are always kept, since "covers nothing new" can't be
+    /// distinguished from "coverage unknown" for them. Mirrors fontconfig's `trim` flag on
+    /// `FcFontSort`.
+    pub fn query_sorted(
+        &self,
+        pattern: &FcPattern,
+        trim: bool,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Vec<(FontMatch, MatchScore)> {
+        let substituted = self.substituted_pattern(pattern, trace);
+        let pattern = &substituted;
+        let mut matches = Vec::new();
+
+        for (stored_pattern, id) in &self.patterns {
+            if Self::query_matches_internal(stored_pattern, pattern, trace) {
+                let metadata = self.metadata.get(id).unwrap_or(stored_pattern);
+                let language_score = Self::calculate_language_score(pattern, metadata);
+                let coverage = Self::calculate_unicode_coverage(&metadata.unicode_ranges);
+                let style_score = Self::calculate_style_score(pattern, metadata);
+                matches.push((*id, language_score, coverage, style_score, metadata.clone()));
+            }
+        }
+
+        // Sort by language score (highest first), then style score (lowest first), then by
+        // unicode coverage (highest first) - same ordering `query`/`query_all` use - and
+        // finally by `FontId` so ties between otherwise-identical candidates resolve the same
+        // way on every call rather than depending on `self.patterns`' iteration order.
+        matches.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.3.cmp(&b.3))
+                .then_with(|| b.2.cmp(&a.2))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+
+        let mut covered_ranges: Vec<UnicodeRange> = Vec::new();
+        let mut results = Vec::new();
+
+        for (id, language_score, coverage, style_score, metadata) in matches {
+            if trim && !metadata.unicode_ranges.is_empty() {
+                let adds_new_coverage = metadata
+                    .unicode_ranges
+                    .iter()
+                    .any(|r| !covered_ranges.iter().any(|c| r.is_subset_of(c)));
+                if !adds_new_coverage {
+                    continue;
+                }
+            }
+            covered_ranges.extend(metadata.unicode_ranges.iter().cloned());
+
+            trace.push(TraceMsg {
+                level: TraceLevel::Debug,
+                path: metadata
+                    .family
+                    .clone()
+                    .unwrap_or_else(|| "<unknown>".to_string()),
+                reason: MatchReason::StyleScored {
+                    distance: style_score,
+                },
+            });
+
+            let fallbacks = self.find_fallbacks(&metadata, trace);
+            let font_match = FontMatch {
+                id,
+                unicode_ranges: metadata.unicode_ranges.clone(),
+                synthesis: FontSynthesis::compute(pattern, &metadata),
+                instantiated_weight: Self::instantiated_weight_for(pattern, &metadata),
+                fallbacks,
+            };
+            let score = MatchScore {
+                language_score,
+                style_score,
+                unicode_coverage: coverage,
+            };
+            results.push((font_match, score));
+        }
+
+        results
+    }
+
+    /// Full diagnostic candidate chain for `pattern`: every stored font that survives
+    /// `query_matches_internal`, in the same rank order `query`/`query_all` would pick from,
+    /// each paired with its score and where its bytes came from. Where `query` only ever
+    /// hands back the single winning `FontId`, this lets a caller see *why* a request like
+    /// `"NotoSansJP"` landed on an unexpected face instead of the one they expected.
+    pub fn explain_query(
+        &self,
+        pattern: &FcPattern,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Vec<MatchExplanation> {
+        let substituted = self.substituted_pattern(pattern, trace);
+        let pattern = &substituted;
         let mut matches = Vec::new();
 
         for (stored_pattern, id) in &self.patterns {
             if Self::query_matches_internal(stored_pattern, pattern, trace) {
                 let metadata = self.metadata.get(id).unwrap_or(stored_pattern);
+                let language_score = Self::calculate_language_score(pattern, metadata);
                 let coverage = Self::calculate_unicode_coverage(&metadata.unicode_ranges);
                 let style_score = Self::calculate_style_score(pattern, metadata);
-                matches.push((*id, coverage, style_score, metadata.clone()));
+                matches.push((*id, language_score, coverage, style_score, metadata.clone()));
             }
         }
 
-        // Sort by style score (lowest first), then by unicode coverage (highest first)
-        matches.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| b.1.cmp(&a.1)));
+        // Same ordering `query`/`query_all`/`query_sorted` already use.
+        matches.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.3.cmp(&b.3))
+                .then_with(|| b.2.cmp(&a.2))
+        });
+
+        matches
+            .into_iter()
+            .map(|(id, language_score, coverage, style_score, metadata)| {
+                let source = if let Some(disk) = self.disk_fonts.get(&id) {
+                    MatchSource::Disk {
+                        path: disk.path.clone(),
+                        font_index: disk.font_index,
+                    }
+                } else {
+                    MatchSource::Memory
+                };
+                MatchExplanation {
+                    id,
+                    reason: Self::explain_reason(pattern, &metadata),
+                    pattern: metadata,
+                    score: MatchScore {
+                        language_score,
+                        style_score,
+                        unicode_coverage: coverage,
+                    },
+                    source,
+                }
+            })
+            .collect()
+    }
+
+    /// Classifies which of `pattern`'s identity fields `candidate` actually satisfied, for
+    /// `explain_query`'s human-readable output. Checked in the same priority `query_matches_
+    /// internal` enforces them in: name, then family, then Unicode coverage; a pattern with
+    /// none of those set is only ever narrowed by style, so it's reported as such.
+    fn explain_reason(pattern: &FcPattern, candidate: &FcPattern) -> String {
+        if let Some(name) = &pattern.name {
+            return if candidate.name.as_deref() == Some(name.as_str()) {
+                "exact name match".to_string()
+            } else {
+                "fuzzy name match".to_string()
+            };
+        }
+        if let Some(family) = &pattern.family {
+            return if candidate.family.as_deref() == Some(family.as_str()) {
+                "exact family match".to_string()
+            } else {
+                "fuzzy family match".to_string()
+            };
+        }
+        if !pattern.unicode_ranges.is_empty() {
+            return "unicode-coverage fallback".to_string();
+        }
+        "generic style match".to_string()
+    }
+
+    /// Builds (or returns the cached) stable, priority-sorted list of all known faces for a
+    /// given base pattern, ordered by `base_pattern.languages` tag overlap (see
+    /// `calculate_language_score`) first, then by style-score proximity, then by total
+    /// Unicode coverage. Language is checked first so that, when two candidates both cover
+    /// the codepoints a fallback search is after, the one actually tuned for the requested
+    /// language/script wins over one that merely has a similar style.
+    ///
+    /// The result is memoized on `base_pattern` so repeated text resolution against the same
+    /// query is effectively O(codepoints) instead of re-scanning every font per codepoint.
+    pub fn build_fallback_order(&self, base_pattern: &FcPattern) -> Vec<FontId> {
+        if let Some(cached) = self.fallback_order_cache.borrow().get(base_pattern) {
+            return cached.clone();
+        }
+
+        let mut scored: Vec<(FontId, usize, i32, u64)> = self
+            .metadata
+            .iter()
+            .map(|(id, pattern)| {
+                let language_score = Self::calculate_language_score(base_pattern, pattern);
+                let style_score = Self::calculate_style_score(base_pattern, pattern);
+                let coverage = Self::calculate_unicode_coverage(&pattern.unicode_ranges);
+                (*id, language_score, style_score, coverage)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.2.cmp(&b.2))
+                .then_with(|| b.3.cmp(&a.3))
+        });
+
+        let order: Vec<FontId> = scored.into_iter().map(|(id, _, _, _)| id).collect();
+        self.fallback_order_cache
+            .borrow_mut()
+            .insert(base_pattern.clone(), order.clone());
+        order
+    }
+
+    /// Every `FontId` whose coverage includes `codepoint`, via `CoverageIndex` - builds and
+    /// memoizes the index on first call rather than per `query_for_text` invocation.
+    fn coverage_candidates(&self, codepoint: u32) -> Vec<FontId> {
+        if self.coverage_index.borrow().is_none() {
+            let index = CoverageIndex::build(&self.metadata);
+            *self.coverage_index.borrow_mut() = Some(index);
+        }
+
+        self.coverage_index
+            .borrow()
+            .as_ref()
+            .map(|index| index.candidates_for(codepoint).to_vec())
+            .unwrap_or_default()
+    }
 
-        matches
-            .into_iter()
-            .map(|(id, _, _, metadata)| {
-                let fallbacks = self.find_fallbacks(&metadata, trace);
+    /// Resolves a single uncovered character against `fallback_order`, narrowing the search to
+    /// `coverage_candidates`' result before walking the (already priority-sorted) fallback list,
+    /// instead of probing every fallback font's coverage in turn. Memoizes the result per
+    /// `(pattern, codepoint)` pair in `char_resolution_cache` so repeated characters in the same
+    /// query are a cache hit rather than a re-walk.
+    fn resolve_char_via_index(
+        &self,
+        pattern: &FcPattern,
+        fallback_order: &[FontId],
+        c: char,
+    ) -> Option<FontId> {
+        let codepoint = c as u32;
+        let key = (pattern.clone(), codepoint);
+        if let Some(cached) = self.char_resolution_cache.borrow().get(&key) {
+            return *cached;
+        }
 
-                FontMatch {
-                    id,
-                    unicode_ranges: metadata.unicode_ranges.clone(),
-                    fallbacks,
-                }
-            })
-            .collect()
+        let candidates = self.coverage_candidates(codepoint);
+        let result = fallback_order.iter().find(|id| candidates.contains(id)).copied();
+
+        self.char_resolution_cache.borrow_mut().insert(key, result);
+        result
     }
 
     fn find_fallbacks(
@@ -921,40 +3541,44 @@ impl FcFontCache {
         pattern: &FcPattern,
         _trace: &mut Vec<TraceMsg>,
     ) -> Vec<FontMatchNoFallback> {
-        let mut candidates = Vec::new();
+        if self.fallback_index.borrow().is_none() {
+            *self.fallback_index.borrow_mut() = Some(FallbackIndex::build(&self.metadata));
+        }
+        let index_ref = self.fallback_index.borrow();
+        let index = index_ref.as_ref().unwrap();
 
-        // Collect all potential fallbacks (excluding original pattern)
+        // Collect all potential fallbacks (excluding original pattern), narrowed via the
+        // range-start index instead of a full scan of every installed font.
         let original_id = self.patterns.get(pattern);
 
-        for (stored_pattern, id) in &self.patterns {
-            // Skip if this is the original pattern
-            if original_id.is_some() && original_id.unwrap() == id {
-                continue;
+        let mut candidate_ids: Vec<FontId> = Vec::new();
+        for p_range in &pattern.unicode_ranges {
+            for id in index.candidates_overlapping(p_range, &self.metadata) {
+                if !candidate_ids.contains(&id) {
+                    candidate_ids.push(id);
+                }
             }
+        }
 
-            // Check if this font supports any of the unicode ranges
-            if !stored_pattern.unicode_ranges.is_empty() {
-                let supports_ranges = pattern.unicode_ranges.iter().any(|p_range| {
-                    stored_pattern
-                        .unicode_ranges
-                        .iter()
-                        .any(|k_range| p_range.overlaps(k_range))
-                });
-
-                if supports_ranges {
-                    let coverage = Self::calculate_unicode_coverage(&stored_pattern.unicode_ranges);
-                    let style_score = Self::calculate_style_score(pattern, stored_pattern);
-                    candidates.push((
-                        FontMatchNoFallback {
-                            id: *id,
-                            unicode_ranges: stored_pattern.unicode_ranges.clone(),
-                        },
-                        coverage,
-                        style_score,
-                        stored_pattern.clone(),
-                    ));
-                }
+        let mut candidates = Vec::new();
+        for id in candidate_ids {
+            if original_id.is_some() && *original_id.unwrap() == id {
+                continue;
             }
+            let Some(stored_pattern) = self.metadata.get(&id) else {
+                continue;
+            };
+            let coverage = *index.coverage.get(&id).unwrap_or(&0);
+            let style_score = Self::calculate_style_score(pattern, stored_pattern);
+            candidates.push((
+                FontMatchNoFallback {
+                    id,
+                    unicode_ranges: stored_pattern.unicode_ranges.clone(),
+                },
+                coverage,
+                style_score,
+                stored_pattern.clone(),
+            ));
         }
 
         // Sort by style score (lowest first), then by coverage (highest first)
@@ -982,6 +3606,29 @@ impl FcFontCache {
         deduplicated
     }
 
+    /// Like `query_for_text`, but lets the caller pin a single BCP-47 language tag for this
+    /// run without mutating `pattern` itself - useful when a mixed-language text is split into
+    /// per-language runs against the same base `FcPattern` (family stack, weight, style), so
+    /// each run can still prefer the culturally correct face among fonts that equally cover
+    /// its codepoints. `language` is prepended to `pattern.languages` (unless already present)
+    /// before matching; `None` behaves exactly like `query_for_text`.
+    pub fn query_for_text_with_language(
+        &self,
+        pattern: &FcPattern,
+        text: &str,
+        language: Option<&str>,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Vec<FontMatch> {
+        match language {
+            Some(lang) if !pattern.languages.iter().any(|l| l.eq_ignore_ascii_case(lang)) => {
+                let mut scoped = pattern.clone();
+                scoped.languages.insert(0, lang.to_string());
+                self.query_for_text(&scoped, text, trace)
+            }
+            _ => self.query_for_text(pattern, text, trace),
+        }
+    }
+
     /// Find fonts that can render the given text, considering Unicode ranges
     pub fn query_for_text(
         &self,
@@ -1020,41 +3667,189 @@ impl FcFontCache {
             }
         }
 
-        // Handle uncovered characters by creating a fallback pattern
+        // Handle uncovered characters by walking the precomputed, priority-sorted fallback
+        // order once instead of re-scanning every font per uncovered codepoint.
         let all_covered = covered_chars.iter().all(|&covered| covered);
         if !all_covered {
-            let mut fallback_pattern = FcPattern::default();
+            for (i, &c) in chars.iter().enumerate() {
+                if covered_chars[i] {
+                    continue;
+                }
+
+                trace.push(TraceMsg {
+                    level: TraceLevel::Warning,
+                    path: "<fallback search>".to_string(),
+                    reason: MatchReason::UnicodeRangeMismatch {
+                        character: c,
+                        ranges: Vec::new(),
+                    },
+                });
+            }
+
+            let fallback_order = self.build_fallback_order(pattern);
 
-            // Add uncovered characters as Unicode ranges
             for (i, &c) in chars.iter().enumerate() {
-                if !covered_chars[i] {
-                    let c_value = c as u32;
-                    fallback_pattern.unicode_ranges.push(UnicodeRange {
-                        start: c_value,
-                        end: c_value,
+                if covered_chars[i] {
+                    continue;
+                }
+
+                let id = match self.resolve_char_via_index(pattern, &fallback_order, c) {
+                    Some(id) => id,
+                    None => continue,
+                };
+
+                covered_chars[i] = true;
+
+                if required_fonts.iter().any(|m| m.id == id) {
+                    continue;
+                }
+
+                if let Some(metadata) = self.metadata.get(&id) {
+                    required_fonts.push(FontMatch {
+                        id,
+                        unicode_ranges: metadata.unicode_ranges.clone(),
+                        fallbacks: Vec::new(),
+                        synthesis: FontSynthesis::compute(pattern, metadata),
+                        instantiated_weight: Self::instantiated_weight_for(pattern, metadata),
                     });
+                }
+            }
+        }
+
+        required_fonts
+    }
+
+    /// Like `query_for_text`, but resolves one font per extended grapheme cluster instead of
+    /// per `char`, so a base codepoint and its combining marks, a ZWJ emoji sequence, or a
+    /// regional-indicator flag pair never land on different faces and shape as tofu. For
+    /// each cluster (see `grapheme_cluster_ranges`), walks the pattern's fallback order and
+    /// picks the first font whose coverage includes every codepoint in the cluster; if none
+    /// covers the whole cluster, falls back to the font covering just the base codepoint and
+    /// keeps the marks attached to it rather than re-resolving them against a different font.
+    ///
+    /// If the fallback order has nothing for a cluster either, tries `last_resort_families` by
+    /// name, then - only if `include_builtin_last_resort` was opted into via
+    /// `with_builtin_last_resort` - substitutes `FontId::BUILTIN_LAST_RESORT`. Either
+    /// substitution is tagged via `TextRunSegment::is_last_resort` rather than silently
+    /// dropping the cluster from the returned segments, as the base `query_for_text` does.
+    pub fn query_for_text_clustered(
+        &self,
+        pattern: &FcPattern,
+        text: &str,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Vec<TextRunSegment> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let fallback_order = self.build_fallback_order(pattern);
+        let mut segments = Vec::new();
+
+        for range in grapheme_cluster_ranges(text) {
+            let codepoints: Vec<char> = text[range.clone()].chars().collect();
+            let base = match codepoints.first() {
+                Some(c) => *c,
+                None => continue,
+            };
+
+            let mut base_font = None;
+            let mut whole_cluster_font = None;
+
+            for &id in &fallback_order {
+                let metadata = match self.metadata.get(&id) {
+                    Some(metadata) => metadata,
+                    None => continue,
+                };
+
+                if base_font.is_none() && metadata.contains_char(base) {
+                    base_font = Some(id);
+                }
+
+                if codepoints.iter().all(|&c| metadata.contains_char(c)) {
+                    whole_cluster_font = Some(id);
+                    break;
+                }
+            }
 
+            let (font, is_last_resort) = match whole_cluster_font.or(base_font) {
+                Some(id) => (id, false),
+                None => {
                     trace.push(TraceMsg {
                         level: TraceLevel::Warning,
-                        path: "<fallback search>".to_string(),
+                        path: "<cluster fallback search>".to_string(),
                         reason: MatchReason::UnicodeRangeMismatch {
-                            character: c,
+                            character: base,
                             ranges: Vec::new(),
                         },
                     });
+
+                    match self.last_resort_font_for_cluster(&codepoints) {
+                        Some(id) => (id, true),
+                        None if self.include_builtin_last_resort => {
+                            (FontId::BUILTIN_LAST_RESORT, true)
+                        }
+                        None => continue,
+                    }
                 }
+            };
+
+            segments.push(TextRunSegment {
+                range,
+                font,
+                is_last_resort,
+            });
+        }
+
+        segments
+    }
+
+    /// Tries `last_resort_families` by name for a cluster `query_for_text_clustered`'s own
+    /// fallback order couldn't cover, same order of preference as `resolve_char_or_last_resort`
+    /// but requiring coverage of every codepoint in the cluster rather than a single `char`.
+    fn last_resort_font_for_cluster(&self, codepoints: &[char]) -> Option<FontId> {
+        for family in &self.last_resort_families {
+            let found = self.patterns.iter().find(|(pattern, _)| {
+                pattern
+                    .family
+                    .as_deref()
+                    .map_or(false, |f| f.eq_ignore_ascii_case(family))
+                    && codepoints.iter().all(|&c| pattern.contains_char(c))
+            });
+            if let Some((_, id)) = found {
+                return Some(*id);
             }
+        }
+        None
+    }
 
-            // Add fallback fonts that weren't already selected
-            let fallback_matches = self.query_all(&fallback_pattern, trace);
-            for font_match in fallback_matches {
-                if !required_fonts.iter().any(|m| m.id == font_match.id) {
-                    required_fonts.push(font_match);
+    /// Splits `text` into runs with a single resolved font per run, merging consecutive
+    /// `query_for_text_clustered` clusters that landed on the same font into one
+    /// `TextRunSegment` instead of leaving the caller to re-merge them. This gives shaping
+    /// clients a ready-to-use run list (analogous to item/run splitting in a full text
+    /// shaper) rather than a per-cluster match set.
+    pub fn itemize_text(
+        &self,
+        pattern: &FcPattern,
+        text: &str,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Vec<TextRunSegment> {
+        let clusters = self.query_for_text_clustered(pattern, text, trace);
+        let mut runs: Vec<TextRunSegment> = Vec::with_capacity(clusters.len());
+
+        for cluster in clusters {
+            match runs.last_mut() {
+                Some(last)
+                    if last.font == cluster.font
+                        && last.is_last_resort == cluster.is_last_resort
+                        && last.range.end == cluster.range.start =>
+                {
+                    last.range.end = cluster.range.end;
                 }
+                _ => runs.push(cluster),
             }
         }
 
-        required_fonts
+        runs
     }
 
     /// Get in-memory font data
@@ -1147,6 +3942,26 @@ impl FcFontCache {
                 pattern.condensed.needs_to_match(),
                 pattern.condensed.matches(&k.condensed),
             ),
+            (
+                "serif",
+                pattern.serif.needs_to_match(),
+                pattern.serif.matches(&k.serif),
+            ),
+            (
+                "scalable",
+                pattern.scalable.needs_to_match(),
+                pattern.scalable.matches(&k.scalable),
+            ),
+            (
+                "outline",
+                pattern.outline.needs_to_match(),
+                pattern.outline.matches(&k.outline),
+            ),
+            (
+                "embedded_bitmap",
+                pattern.embedded_bitmap.needs_to_match(),
+                pattern.embedded_bitmap.matches(&k.embedded_bitmap),
+            ),
         ];
 
         for (property_name, needs_to_match, matches) in style_properties {
@@ -1163,6 +3978,19 @@ impl FcFontCache {
                         format!("{:?}", pattern.condensed),
                         format!("{:?}", k.condensed),
                     ),
+                    "serif" => (format!("{:?}", pattern.serif), format!("{:?}", k.serif)),
+                    "scalable" => (
+                        format!("{:?}", pattern.scalable),
+                        format!("{:?}", k.scalable),
+                    ),
+                    "outline" => (
+                        format!("{:?}", pattern.outline),
+                        format!("{:?}", k.outline),
+                    ),
+                    "embedded_bitmap" => (
+                        format!("{:?}", pattern.embedded_bitmap),
+                        format!("{:?}", k.embedded_bitmap),
+                    ),
                     _ => (String::new(), String::new()),
                 };
 
@@ -1182,7 +4010,11 @@ impl FcFontCache {
             }
         }
 
-        // Check weight
+        // Weight is no longer a hard filter: CSS Fonts Level 4 resolves a mismatched weight
+        // to the nearest available one within the matched family rather than discarding it,
+        // so a request for e.g. `Medium` still returns a family that only ships Regular/Bold.
+        // `calculate_style_score`'s `weight_distance` ranks the surviving candidates by how
+        // far they are from the request, so the nearest weight still wins the sort.
         if pattern.weight != FcWeight::Normal && pattern.weight != k.weight {
             trace.push(TraceMsg {
                 level: TraceLevel::Info,
@@ -1190,12 +4022,11 @@ impl FcFontCache {
                     .name
                     .as_ref()
                     .map_or_else(|| "<unknown>".to_string(), |s| s.clone()),
-                reason: MatchReason::WeightMismatch {
+                reason: MatchReason::WeightSubstituted {
                     requested: pattern.weight,
                     found: k.weight,
                 },
             });
-            return false;
         }
 
         // Check stretch
@@ -1214,6 +4045,22 @@ impl FcFontCache {
             return false;
         }
 
+        // Check spacing (a finer-grained classification than the `monospace` bool above)
+        if !pattern.spacing.satisfies(k.spacing) {
+            trace.push(TraceMsg {
+                level: TraceLevel::Info,
+                path: k
+                    .name
+                    .as_ref()
+                    .map_or_else(|| "<unknown>".to_string(), |s| s.clone()),
+                reason: MatchReason::SpacingMismatch {
+                    requested: pattern.spacing,
+                    found: k.spacing,
+                },
+            });
+            return false;
+        }
+
         // Check unicode ranges if specified
         if !pattern.unicode_ranges.is_empty() {
             let mut has_overlap = false;
@@ -1246,8 +4093,92 @@ impl FcFontCache {
             }
         }
 
+        // Check language coverage, if the face advertises any languages at all.
+        // Faces with no language data are never rejected on this basis alone.
+        if !pattern.languages.is_empty() && !k.languages.is_empty() {
+            if Self::calculate_language_score(pattern, k) == 0 {
+                trace.push(TraceMsg {
+                    level: TraceLevel::Info,
+                    path: k
+                        .name
+                        .as_ref()
+                        .map_or_else(|| "<unknown>".to_string(), |s| s.clone()),
+                    reason: MatchReason::LanguageMismatch {
+                        requested: pattern.languages.clone(),
+                        covered: k.languages.clone(),
+                    },
+                });
+                return false;
+            }
+        }
+
+        // Check required OpenType script coverage. Unlike the language check above, a
+        // candidate with no scanned `script_coverage` is rejected rather than let through -
+        // there's no positive signal it can shape the requested script.
+        if !pattern.required_scripts.is_empty() {
+            let covers_all = pattern
+                .required_scripts
+                .iter()
+                .all(|tag| k.script_coverage.contains(tag));
+
+            if !covers_all {
+                trace.push(TraceMsg {
+                    level: TraceLevel::Info,
+                    path: k
+                        .name
+                        .as_ref()
+                        .map_or_else(|| "<unknown>".to_string(), |s| s.clone()),
+                    reason: MatchReason::ScriptMismatch {
+                        requested: pattern.required_scripts.clone(),
+                        covered: k.script_coverage.clone(),
+                    },
+                });
+                return false;
+            }
+        }
+
         true
     }
+
+    /// Scores a single requested BCP-47 tag (e.g. `"zh-Hans"`) against one `candidate` tag:
+    /// an exact match (case-insensitive) scores best, a shared primary subtag (`"zh"` vs
+    /// `"zh-Hant"`) scores next, and anything else scores zero. Used by
+    /// `calculate_language_score` to rank fonts that both cover the needed codepoints but are
+    /// tuned for different languages/scripts of the same script family.
+    fn language_tag_tier(requested: &str, candidate: &str) -> usize {
+        if requested.eq_ignore_ascii_case(candidate) {
+            return 2;
+        }
+
+        let requested_primary = requested.split('-').next().unwrap_or(requested);
+        let candidate_primary = candidate.split('-').next().unwrap_or(candidate);
+        if requested_primary.eq_ignore_ascii_case(candidate_primary) {
+            return 1;
+        }
+
+        0
+    }
+
+    /// Sums, over each of `pattern`'s requested language tags, the best `language_tag_tier`
+    /// found among `candidate`'s tags - so a font carrying an exact tag match for every
+    /// requested language outranks one that only shares primary subtags, which in turn
+    /// outranks one with no language data at all. Mirrors fontconfig's language-aware
+    /// `FcFontSort` ordering.
+    fn calculate_language_score(pattern: &FcPattern, candidate: &FcPattern) -> usize {
+        pattern
+            .languages
+            .iter()
+            .map(|requested| {
+                candidate
+                    .languages
+                    .iter()
+                    .map(|available| Self::language_tag_tier(requested, available))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+
     /// Find fallback fonts for a given pattern
     // Helper to calculate total unicode coverage
     fn calculate_unicode_coverage(ranges: &[UnicodeRange]) -> u64 {
@@ -1257,20 +4188,86 @@ impl FcFontCache {
             .sum()
     }
 
+    /// Scores how well `available` covers `requested`: the summed size of every overlap
+    /// between a requested range and an available one. Higher is a better match.
+    fn calculate_unicode_compatibility(requested: &[UnicodeRange], available: &[UnicodeRange]) -> i32 {
+        let mut score = 0i32;
+
+        for r in requested {
+            for a in available {
+                if r.overlaps(a) {
+                    let overlap_start = r.start.max(a.start);
+                    let overlap_end = r.end.min(a.end);
+                    score += (overlap_end.saturating_sub(overlap_start) + 1) as i32;
+                }
+            }
+        }
+
+        score
+    }
+
+    /// Splits a font name/family string into lowercase-able tokens for fuzzy matching,
+    /// e.g. `"Noto Sans CJK SC"` -> `["Noto", "Sans", "CJK", "SC"]`.
+    fn extract_font_name_tokens(name: &str) -> Vec<String> {
+        name.split(|c: char| !c.is_alphanumeric())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Checks `candidate` against `requested`'s weight/stretch/italic/oblique for an exact
+    /// match, as `MatchStrictness::ExactStyle`/`FcPattern::exact_style` require. Pushes a
+    /// `MatchReason::ExactStyleMismatch` trace entry and returns `false` on any mismatch,
+    /// rather than letting the caller substitute the closest available style.
+    fn exact_style_matches(requested: &FcPattern, candidate: &FcPattern, trace: &mut Vec<TraceMsg>) -> bool {
+        if candidate.weight == requested.weight
+            && candidate.stretch == requested.stretch
+            && candidate.italic == requested.italic
+            && candidate.oblique == requested.oblique
+        {
+            return true;
+        }
+
+        trace.push(TraceMsg {
+            level: TraceLevel::Info,
+            path: candidate
+                .name
+                .as_ref()
+                .map_or_else(|| "<unknown>".to_string(), Clone::clone),
+            reason: MatchReason::ExactStyleMismatch {
+                requested: format!(
+                    "weight={:?} stretch={:?} italic={:?} oblique={:?}",
+                    requested.weight, requested.stretch, requested.italic, requested.oblique
+                ),
+                found: format!(
+                    "weight={:?} stretch={:?} italic={:?} oblique={:?}",
+                    candidate.weight, candidate.stretch, candidate.italic, candidate.oblique
+                ),
+            },
+        });
+        false
+    }
+
     fn calculate_style_score(original: &FcPattern, candidate: &FcPattern) -> i32 {
 
         let mut score = 0_i32;
 
         // Weight calculation with special handling for bold property
-        if (original.bold == PatternMatch::True && candidate.weight == FcWeight::Bold)
+        if let Some(requested) = original.weight_value {
+            // An exact numeric weight was requested: score off the candidate's real weight
+            // (variable `wght` axis if it has one, else its raw `usWeightClass`) instead of
+            // the coarse `FcWeight` bucket - see `FcPattern::weight_value`.
+            score += Self::weight_value_distance(requested, candidate);
+        } else if (original.bold == PatternMatch::True && candidate.weight == FcWeight::Bold)
             || (original.bold == PatternMatch::False && candidate.weight != FcWeight::Bold)
         {
             // No weight penalty when bold is requested and font has Bold weight
             // No weight penalty when non-bold is requested and font has non-Bold weight
         } else {
-            // Apply normal weight difference penalty
-            let weight_diff = (original.weight as i32 - candidate.weight as i32).abs();
-            score += weight_diff as i32;
+            // Apply the CSS Fonts Level 4 nearest-weight penalty: candidates on the preferred
+            // side of the requested weight (see `FcPattern::weight_distance`) always outrank
+            // ones on the other side, so the sort order agrees with `FcWeight::find_best_match`.
+            score += FcPattern::weight_distance(original.weight, candidate.weight);
         }
 
         // Stretch calculation with special handling for condensed property
@@ -1292,6 +4289,7 @@ impl FcFontCache {
             (original.bold, candidate.bold, 300, 150),
             (original.monospace, candidate.monospace, 100, 50),
             (original.condensed, candidate.condensed, 100, 50),
+            (original.serif, candidate.serif, 150, 75),
         ];
 
         for (orig, cand, mismatch_penalty, dontcare_penalty) in style_props {
@@ -1309,14 +4307,64 @@ impl FcFontCache {
             }
         }
 
+        // `spacing` is a finer-grained axis than the `monospace` bool scored above (e.g.
+        // `Dual`/`CharCell` vs plain `Mono`) and is already a hard filter in
+        // `query_matches_internal`/`resolve_font_chain_uncached` (`FcSpacing::satisfies`), so a
+        // candidate that fails outright never reaches this point. Among the candidates that do
+        // satisfy it, though - a `Mono` request accepts both `Mono` and `CharCell` faces - the
+        // exact level should still outrank the coarser one it's only nominally compatible with.
+        if original.spacing != FcSpacing::DontCare && original.spacing != candidate.spacing {
+            score += 50;
+        }
+
         score
     }
+
+    /// Style-score contribution for an exact numeric `requested` weight against `candidate`:
+    /// `0` if `requested` falls inside `candidate.weight_axis` (the variable font can be
+    /// instantiated at that exact weight), else `|requested - candidate's real weight|` using
+    /// `candidate.weight_value` when recorded, falling back to the coarse `FcWeight` bucket
+    /// for faces that don't carry a raw `usWeightClass` (e.g. a synthetic query pattern).
+    fn weight_value_distance(requested: u16, candidate: &FcPattern) -> i32 {
+        if let Some((min, _default, max)) = candidate.weight_axis {
+            if requested >= min && requested <= max {
+                return 0;
+            }
+            let axis_distance = if requested < min {
+                min - requested
+            } else {
+                requested - max
+            };
+            return axis_distance as i32;
+        }
+
+        let candidate_value = candidate.weight_value.unwrap_or(candidate.weight as u16);
+        (requested as i32 - candidate_value as i32).abs()
+    }
+
+    /// The exact `wght` axis coordinate to instantiate `metadata`'s variable font at to satisfy
+    /// `pattern` precisely, or `None` if `pattern` didn't request an exact `weight_value` or
+    /// `metadata` isn't variable (or doesn't cover that weight) - see
+    /// `FontMatch::instantiated_weight`.
+    fn instantiated_weight_for(pattern: &FcPattern, metadata: &FcPattern) -> Option<u16> {
+        let requested = pattern.weight_value?;
+        let (min, _default, max) = metadata.weight_axis?;
+        (requested >= min && requested <= max).then_some(requested)
+    }
 }
 
+/// Scans `/etc/fonts/fonts.conf` (and whatever it `<include>`s) the same way `ParseFontsConf`
+/// always has for `<dir>` font directories, while also running `parse_fontconfig_config_xml`
+/// over the same documents so `<alias>`/`<match>` rules from the *system's own* fonts.conf take
+/// effect in `build()` - previously only `build_with_config`'s explicit paths got alias
+/// substitution, silently dropping whatever `/etc/fonts/fonts.conf` itself declared.
 #[cfg(all(feature = "std", feature = "parsing"))]
-fn FcScanDirectories() -> Option<Vec<(FcPattern, FcFontPath)>> {
+fn FcScanDirectories() -> Option<(
+    Vec<(FcPattern, FcFontPath)>,
+    BTreeMap<String, Vec<String>>,
+    Vec<FcMatchRule>,
+)> {
     use std::fs;
-    use std::path::Path;
 
     const BASE_FONTCONFIG_PATH: &str = "/etc/fonts/fonts.conf";
 
@@ -1326,6 +4374,8 @@ fn FcScanDirectories() -> Option<Vec<(FcPattern, FcFontPath)>> {
 
     let mut font_paths = Vec::with_capacity(32);
     let mut paths_to_visit = vec![(None, PathBuf::from(BASE_FONTCONFIG_PATH))];
+    let mut aliases: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut rules: Vec<FcMatchRule> = Vec::new();
 
     while let Some((prefix, path_to_visit)) = paths_to_visit.pop() {
         let path = match process_path(&prefix, path_to_visit, true) {
@@ -1344,6 +4394,8 @@ fn FcScanDirectories() -> Option<Vec<(FcPattern, FcFontPath)>> {
                 Err(_) => continue,
             };
 
+            parse_fontconfig_config_xml(&xml_utf8, &mut aliases, &mut rules);
+
             if ParseFontsConf(&xml_utf8, &mut paths_to_visit, &mut font_paths).is_none() {
                 continue;
             }
@@ -1390,7 +4442,8 @@ fn FcScanDirectories() -> Option<Vec<(FcPattern, FcFontPath)>> {
         return None;
     }
 
-    Some(FcScanDirectoriesInner(&font_paths))
+    rules_dedup_stable(&mut rules);
+    Some((FcScanDirectoriesInner(&font_paths), aliases, rules))
 }
 
 // Parses the fonts.conf file
@@ -1488,25 +4541,248 @@ fn ParseFontsConf(
                     _ => continue,
                 }
 
-                is_in_include = false;
-                is_in_dir = false;
-                current_path = None;
-                current_prefix = None;
-            }
-            _ => {}
-        }
+                is_in_include = false;
+                is_in_dir = false;
+                current_path = None;
+                current_prefix = None;
+            }
+            _ => {}
+        }
+    }
+
+    Some(())
+}
+
+/// Reads the cleartext (ASCII) header out of a `.pfb`'s segmented binary framing: each segment
+/// is a `0x80` marker byte, a type byte (`1` = ASCII, `2` = binary, `3` = EOF) and a 4-byte
+/// little-endian length. The font dictionary fields this crate cares about (`/FontName`,
+/// `/FamilyName`, ...) all live in the first ASCII segment, so concatenating type-`1` segments
+/// up to the first type-`2`/`3` one is enough - we never need the encrypted binary charstrings.
+fn read_pfb_cleartext(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.first() != Some(&0x80) {
+        return None;
+    }
+
+    let mut out = Vec::new();
+    let mut offset = 0usize;
+
+    while offset + 6 <= bytes.len() && bytes[offset] == 0x80 {
+        let segment_type = bytes[offset + 1];
+        if segment_type != 1 {
+            // Binary charstrings or EOF marker: the cleartext header is always the leading
+            // run of ASCII segments, so there's nothing more to read once it ends.
+            break;
+        }
+
+        let len = u32::from_le_bytes([
+            bytes[offset + 2],
+            bytes[offset + 3],
+            bytes[offset + 4],
+            bytes[offset + 5],
+        ]) as usize;
+        let start = offset + 6;
+        let end = (start + len).min(bytes.len());
+        out.extend_from_slice(&bytes[start..end]);
+        offset = end;
+    }
+
+    if out.is_empty() {
+        None
+    } else {
+        Some(out)
+    }
+}
+
+/// Looks up `/key` inside a Type1 cleartext header (`/FontName /Foo-Bold def`,
+/// `/FamilyName (Foo) def`, `/ItalicAngle -12 def`), returning the value with its PostScript
+/// name slash or string parens stripped. Not a general PostScript tokenizer - just enough to
+/// pull the handful of `/FontInfo` fields `FcParseType1Font` needs.
+fn type1_header_value(header: &str, key: &str) -> Option<String> {
+    let needle = format!("/{}", key);
+    let after = header[header.find(&needle)? + needle.len()..].trim_start();
+
+    if let Some(rest) = after.strip_prefix('(') {
+        let end = rest.find(')')?;
+        Some(rest[..end].trim().to_string())
+    } else if let Some(rest) = after.strip_prefix('/') {
+        let end = rest.find(|c: char| c.is_whitespace()).unwrap_or(rest.len());
+        Some(rest[..end].to_string())
+    } else {
+        let end = after.find(|c: char| c.is_whitespace()).unwrap_or(after.len());
+        Some(after[..end].to_string())
+    }
+}
+
+/// Parses an AFM's `StartFontMetrics`/`EndCharMetrics` header into its `Key Value` pairs
+/// (`FontName`, `FamilyName`, `Weight`, `IsFixedPitch`, `CharacterSet`, ...) - stops at
+/// `StartCharMetrics` since everything after that is per-glyph metric lines, not header fields.
+fn parse_afm_header(afm_text: &str) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+
+    for line in afm_text.lines() {
+        let line = line.trim();
+        if line.starts_with("StartCharMetrics") {
+            break;
+        }
+        let Some(sep) = line.find(char::is_whitespace) else {
+            continue;
+        };
+        let key = &line[..sep];
+        let value = line[sep..].trim();
+        if !key.is_empty() && !value.is_empty() {
+            fields.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    fields
+}
+
+/// Derives a coverage set from an AFM's `C <code> ; ...` char-metric lines, the closest thing a
+/// Type1 face has to a `cmap`: `<code>` is the Adobe Standard/Symbol encoding codepoint the glyph
+/// is assigned to (`-1` for unencoded glyphs, which are skipped). Mirrors `extract_cmap_coverage`
+/// - sorted, merged inclusive runs - so `FcPattern::contains_char` treats it identically.
+fn extract_afm_coverage(afm_text: &str) -> Vec<UnicodeRange> {
+    let mut codepoints: Vec<u32> = afm_text
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = line.strip_prefix("C ")?;
+            let code_str = rest.split(';').next()?.trim();
+            code_str.parse::<i32>().ok()
+        })
+        .filter(|&code| code >= 0)
+        .map(|code| code as u32)
+        .collect();
+
+    codepoints.sort_unstable();
+    codepoints.dedup();
+
+    let mut ranges: Vec<UnicodeRange> = Vec::new();
+    for cp in codepoints {
+        match ranges.last_mut() {
+            Some(last) if cp == last.end + 1 => last.end = cp,
+            _ => ranges.push(UnicodeRange { start: cp, end: cp }),
+        }
+    }
+    ranges
+}
+
+/// Parses a PostScript Type1 font (`.pfa`/`.pfb`) into an `FcPattern`, the way `FcParseFont`
+/// does for OpenType/TrueType - Type1 has no `cmap`/OS-2/name tables allsorts understands, so
+/// this reads the cleartext `/FontInfo` dictionary directly instead (see `type1_header_value`),
+/// and fills in whatever an adjacent `.afm` sidecar adds (glyph coverage, and a fallback for any
+/// header field the `.pfb`/`.pfa` itself omitted).
+#[cfg(all(feature = "std", feature = "parsing"))]
+fn FcParseType1Font(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
+    let bytes = std::fs::read(filepath).ok()?;
+    let cleartext = if bytes.first() == Some(&0x80) {
+        read_pfb_cleartext(&bytes)?
+    } else {
+        bytes
+    };
+    let header = String::from_utf8_lossy(&cleartext);
+
+    let font_name = type1_header_value(&header, "FontName")?;
+
+    let afm_text = std::fs::read_to_string(filepath.with_extension("afm")).ok();
+    let afm_header = afm_text.as_deref().map(parse_afm_header).unwrap_or_default();
+
+    let family = type1_header_value(&header, "FamilyName")
+        .or_else(|| afm_header.get("FamilyName").cloned())
+        .unwrap_or_else(|| font_name.clone());
+
+    let weight_str = type1_header_value(&header, "Weight").or_else(|| afm_header.get("Weight").cloned());
+    let weight = weight_str
+        .as_deref()
+        .and_then(|w| parse_fc_weight(w).ok())
+        .unwrap_or(FcWeight::Normal);
+
+    let italic_angle: f32 = type1_header_value(&header, "ItalicAngle")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.0);
+
+    let is_fixed_pitch = type1_header_value(&header, "isFixedPitch")
+        .map(|v| v == "true")
+        .or_else(|| {
+            afm_header
+                .get("IsFixedPitch")
+                .map(|v| v.eq_ignore_ascii_case("true"))
+        })
+        .unwrap_or(false);
+
+    let cmap_coverage = afm_text.as_deref().map(extract_afm_coverage).unwrap_or_default();
+
+    let mut name = font_name.clone();
+    let mut family = family;
+    if name.starts_with('.') {
+        name = name[1..].to_string();
+    }
+    if family.starts_with('.') {
+        family = family[1..].to_string();
     }
 
-    Some(())
+    let pattern = FcPattern {
+        name: Some(name),
+        family: Some(family.clone()),
+        bold: if matches!(weight, FcWeight::Bold | FcWeight::ExtraBold | FcWeight::Black) {
+            PatternMatch::True
+        } else {
+            PatternMatch::False
+        },
+        italic: if italic_angle != 0.0 {
+            PatternMatch::True
+        } else {
+            PatternMatch::False
+        },
+        monospace: if is_fixed_pitch {
+            PatternMatch::True
+        } else {
+            PatternMatch::False
+        },
+        spacing: if is_fixed_pitch {
+            FcSpacing::Mono
+        } else {
+            FcSpacing::Proportional
+        },
+        serif: classify_serif(&Default::default(), Some(family.as_str())),
+        weight,
+        unicode_ranges: cmap_coverage.clone(),
+        cmap_coverage,
+        metadata: FcFontMetadata {
+            postscript_name: Some(font_name),
+            font_family: Some(family),
+            full_name: afm_header.get("FullName").cloned(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    Some(vec![(
+        pattern,
+        FcFontPath {
+            path: filepath.to_string_lossy().to_string(),
+            font_index: 0,
+        },
+    )])
 }
 
 // Remaining implementation for font scanning, parsing, etc.
 #[cfg(all(feature = "std", feature = "parsing"))]
 fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
+    if matches!(
+        filepath
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase())
+            .as_deref(),
+        Some("pfa") | Some("pfb")
+    ) {
+        return FcParseType1Font(filepath);
+    }
+
     use allsorts_subset_browser::{
         binary::read::ReadScope,
         font_data::FontData,
-        get_name::fontcode_get_name,
         post::PostTable,
         tables::{
             os2::Os2, FontTableProvider, HeadTable, HheaTable, HmtxTable, MaxpTable, NameTable,
@@ -1514,7 +4790,6 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
         tag,
     };
     #[cfg(all(not(target_family = "wasm"), feature = "std"))]
-    use mmapio::MmapOptions;
     use std::collections::BTreeSet;
     use std::fs::File;
 
@@ -1525,7 +4800,7 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
     let file = File::open(filepath).ok()?;
 
     #[cfg(all(not(target_family = "wasm"), feature = "std"))]
-    let font_bytes = unsafe { MmapOptions::new().map(&file).ok()? };
+    let font_bytes = unsafe { memmap2::Mmap::map(&file).ok()? };
 
     #[cfg(not(all(not(target_family = "wasm"), feature = "std")))]
     let font_bytes = std::fs::read(filepath).ok()?;
@@ -1556,10 +4831,14 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
         let is_italic = head_table.is_italic();
         let mut detected_monospace = None;
 
+        let mut underline_position = 0i16;
+        let mut underline_thickness = 0i16;
         let post_data = provider.table_data(tag::POST).ok()??;
         if let Ok(post_table) = ReadScope::new(&post_data).read::<PostTable>() {
             // isFixedPitch here - https://learn.microsoft.com/en-us/typography/opentype/spec/post#header
             detected_monospace = Some(post_table.header.is_fixed_pitch != 0);
+            underline_position = post_table.header.underline_position;
+            underline_thickness = post_table.header.underline_thickness;
         }
 
         // Get font properties from OS/2 table
@@ -1573,7 +4852,10 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
             .fs_selection
             .contains(allsorts_subset_browser::tables::os2::FsSelection::OBLIQUE);
         let weight = FcWeight::from_u16(os2_table.us_weight_class);
+        let weight_value = Some(os2_table.us_weight_class);
+        let weight_axis = extract_weight_axis(&provider);
         let stretch = FcStretch::from_u16(os2_table.us_width_class);
+        let (scalable, outline, embedded_bitmap) = detect_outline_tables(&provider);
 
         // Extract unicode ranges
         let mut unicode_ranges = Vec::new();
@@ -1612,6 +4894,40 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
             }
         }
 
+        // Derive a rough set of supported BCP-47 language tags from the OS/2 codepage ranges
+        let languages = extract_languages_from_codepage_range(
+            os2_table.ul_code_page_range1,
+            os2_table.ul_code_page_range2,
+        );
+
+        // Real per-glyph coverage from the `cmap` table, as opposed to the coarse OS/2
+        // block hints above; empty if the table is missing or in a format we don't parse.
+        let cmap_coverage = extract_cmap_coverage(&provider).unwrap_or_default();
+
+        // OpenType script tags this coverage maps to - see `fonts_for_script`.
+        let script_coverage = extract_script_coverage(&cmap_coverage);
+
+        // Vertical metrics for fallback-metric-override computation (see `FontMetrics`).
+        // Read independently of the monospace detection below, which only consults `hhea`
+        // when PANOSE doesn't already answer it.
+        let metrics = provider
+            .table_data(tag::HHEA)
+            .ok()
+            .flatten()
+            .and_then(|hhea_data| ReadScope::new(&hhea_data).read::<HheaTable>().ok())
+            .map(|hhea_table| FontMetrics {
+                units_per_em: head_table.units_per_em,
+                ascender: hhea_table.ascender,
+                descender: hhea_table.descender,
+                line_gap: hhea_table.line_gap,
+                cap_height: os2_table.s_cap_height.unwrap_or(0),
+                x_height: os2_table.sx_height.unwrap_or(0),
+                underline_position,
+                underline_thickness,
+                average_advance: os2_table.x_avg_char_width,
+            })
+            .unwrap_or_default();
+
         // If no monospace detection yet, check using hmtx
         if detected_monospace.is_none() {
             // Try using PANOSE classification
@@ -1647,6 +4963,7 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
         }
 
         let is_monospace = detected_monospace.unwrap_or(false);
+        let spacing = detect_spacing(&provider, is_monospace);
 
         let name_data = provider.table_data(tag::NAME).ok()??.into_owned();
         let name_table = ReadScope::new(&name_data).read::<NameTable>().ok()?;
@@ -1660,13 +4977,13 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
             .filter_map(|name_record| {
                 let name_id = name_record.name_id;
                 if name_id == FONT_SPECIFIER_FAMILY_ID {
-                    let family = fontcode_get_name(&name_data, FONT_SPECIFIER_FAMILY_ID).ok()??;
+                    let family = get_name_string(&name_data, FONT_SPECIFIER_FAMILY_ID)?;
                     f_family = Some(family);
                     None
                 } else if name_id == FONT_SPECIFIER_NAME_ID {
-                    let family = f_family.as_ref()?;
-                    let name = fontcode_get_name(&name_data, FONT_SPECIFIER_NAME_ID).ok()??;
-                    if name.to_bytes().is_empty() {
+                    let family = f_family.as_ref()?.clone();
+                    let name = get_name_string(&name_data, FONT_SPECIFIER_NAME_ID)?;
+                    if name.is_empty() {
                         None
                     } else {
                         // Initialize metadata structure
@@ -1711,15 +5028,23 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
                             get_name_string(&name_data, NAME_ID_PREFERRED_FAMILY);
                         metadata.preferred_subfamily =
                             get_name_string(&name_data, NAME_ID_PREFERRED_SUBFAMILY);
+                        metadata.metrics = metrics;
 
-                        let mut name = String::from_utf8_lossy(name.to_bytes()).to_string();
-                        let mut family = String::from_utf8_lossy(family.as_bytes()).to_string();
+                        let foundry = extract_foundry(
+                            &os2_table.ach_vend_id.to_string(),
+                            metadata.copyright.as_deref(),
+                            metadata.trademark.as_deref(),
+                        );
+
+                        let mut name = name;
+                        let mut family = family;
                         if name.starts_with(".") {
                             name = name[1..].to_string();
                         }
                         if family.starts_with(".") {
                             family = family[1..].to_string();
                         }
+                        let serif = classify_serif(&os2_table.panose, Some(family.as_str()));
                         Some((
                             FcPattern {
                                 name: Some(name),
@@ -1744,15 +5069,30 @@ fn FcParseFont(filepath: &PathBuf) -> Option<Vec<(FcPattern, FcFontPath)>> {
                                 } else {
                                     PatternMatch::False
                                 },
+                                fullname: metadata.full_name.clone(),
+                                spacing,
+                                serif,
                                 condensed: if stretch <= FcStretch::Condensed {
                                     PatternMatch::True
                                 } else {
                                     PatternMatch::False
                                 },
                                 weight,
+                                weight_value,
+                                weight_axis,
                                 stretch,
+                                exact_style: false,
                                 unicode_ranges: unicode_ranges.clone(),
+                                cmap_coverage: cmap_coverage.clone(),
+                                script_coverage: script_coverage.clone(),
                                 metadata,
+                                foundry,
+                                languages: languages.clone(),
+                                required_scripts: Vec::new(),
+                                scalable,
+                                outline,
+                                embedded_bitmap,
+                                unknown_properties: Vec::new(),
                             },
                             font_index,
                         ))
@@ -1819,7 +5159,7 @@ fn FcScanDirectoriesInner(paths: &[(Option<String>, String)]) -> Vec<(FcPattern,
 #[cfg(all(feature = "std", feature = "parsing"))]
 fn FcScanSingleDirectoryRecursive(dir: PathBuf) -> Vec<(FcPattern, FcFontPath)> {
     let mut files_to_parse = Vec::new();
-    let mut dirs_to_parse = vec![dir];
+    let mut dirs_to_parse = vec![dir.clone()];
 
     'outer: loop {
         let mut new_dirs_to_parse = Vec::new();
@@ -1851,7 +5191,97 @@ fn FcScanSingleDirectoryRecursive(dir: PathBuf) -> Vec<(FcPattern, FcFontPath)>
         }
     }
 
-    FcParseFontFiles(&files_to_parse)
+    #[cfg(feature = "cache")]
+    {
+        FcScanFilesWithCache(&dir, &files_to_parse)
+    }
+    #[cfg(not(feature = "cache"))]
+    {
+        FcParseFontFiles(&files_to_parse)
+    }
+}
+
+/// Like `FcParseFontFiles`, but consults (and updates) an on-disk cache of previously scanned
+/// faces for `dir`, keyed by each file's path, so that a re-scan where a file's size and
+/// modification time haven't changed can reuse its cached patterns instead of re-parsing it.
+///
+/// The cache lives in its own file per top-level scanned directory (see
+/// `font_scan_cache_path_for_dir`) rather than one shared manifest, so that directories scanned
+/// concurrently by `FcScanDirectoriesInner`'s rayon pass never contend over the same file.
+#[cfg(all(feature = "std", feature = "parsing", feature = "cache"))]
+fn FcScanFilesWithCache(dir: &Path, files_to_parse: &[PathBuf]) -> Vec<(FcPattern, FcFontPath)> {
+    use std::collections::BTreeSet;
+
+    let cache_path = font_scan_cache_path_for_dir(dir);
+    let existing_cache = cache_path.as_deref().and_then(load_cache);
+
+    let mut reused = Vec::new();
+    let mut files_needing_parse = Vec::new();
+
+    for file in files_to_parse {
+        let key = file.to_string_lossy().to_string();
+        let stat = stat_file_metadata(file);
+        let cached_entry = existing_cache
+            .as_ref()
+            .and_then(|cache| cache.entries.get(&key))
+            .filter(|entry| stat == Some((entry.mtime_secs, entry.file_size)));
+
+        match cached_entry {
+            Some(entry) => reused.extend(entry.faces.iter().map(|(pattern, font_index)| {
+                (
+                    pattern.clone(),
+                    FcFontPath {
+                        path: key.clone(),
+                        font_index: *font_index,
+                    },
+                )
+            })),
+            None => files_needing_parse.push(file.clone()),
+        }
+    }
+
+    let newly_parsed = FcParseFontFiles(&files_needing_parse);
+
+    if let Some(cache_path) = cache_path {
+        let mut entries = existing_cache.map(|c| c.entries).unwrap_or_default();
+
+        for file in &files_needing_parse {
+            let key = file.to_string_lossy().to_string();
+            let Some((mtime_secs, file_size)) = stat_file_metadata(file) else {
+                continue;
+            };
+            let faces = newly_parsed
+                .iter()
+                .filter(|(_, font_path)| font_path.path == key)
+                .map(|(pattern, font_path)| (pattern.clone(), font_path.font_index))
+                .collect();
+            entries.insert(
+                key,
+                FontScanCacheEntry {
+                    mtime_secs,
+                    file_size,
+                    faces,
+                },
+            );
+        }
+
+        // Drop entries for files that are no longer present under this directory.
+        let live_keys: BTreeSet<String> = files_to_parse
+            .iter()
+            .map(|f| f.to_string_lossy().to_string())
+            .collect();
+        entries.retain(|key, _| live_keys.contains(key));
+
+        save_cache(
+            &cache_path,
+            &FontScanCache {
+                version: FontScanCache::CURRENT_VERSION,
+                entries,
+            },
+        );
+    }
+
+    reused.into_iter().chain(newly_parsed).collect()
 }
 
 #[cfg(all(feature = "std", feature = "parsing"))]
@@ -1878,6 +5308,215 @@ fn FcParseFontFiles(files_to_parse: &[PathBuf]) -> Vec<(FcPattern, FcFontPath)>
     result.into_iter().flat_map(|f| f.into_iter()).collect()
 }
 
+// ── On-disk font scan cache ─────────────────────────────────────────────────
+
+/// On-disk cache of a single scanned directory's font-scan results, keyed by each file's path.
+/// See `FcScanFilesWithCache`.
+#[cfg(feature = "cache")]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct FontScanCache {
+    /// Cache format version (bump on breaking changes).
+    version: u32,
+    /// Entries: file path → cached scan result.
+    entries: BTreeMap<String, FontScanCacheEntry>,
+}
+
+#[cfg(feature = "cache")]
+impl FontScanCache {
+    const CURRENT_VERSION: u32 = 1;
+}
+
+/// A single cached font file's scan result.
+#[cfg(feature = "cache")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FontScanCacheEntry {
+    /// File modification time (seconds since epoch) at the time of caching.
+    mtime_secs: u64,
+    /// File size in bytes at the time of caching.
+    file_size: u64,
+    /// The patterns and face indices `FcParseFont` returned for this file.
+    faces: Vec<(FcPattern, usize)>,
+}
+
+/// Get the file's cache path for `dir`, namespaced by a hash of `dir` so that every scanned
+/// top-level font directory gets its own independent cache file - this, rather than one shared
+/// manifest, is what lets directories be scanned concurrently without contending over the same
+/// file. Uses the same `process_path` XDG-resolution machinery as the rest of font discovery.
+#[cfg(feature = "cache")]
+fn font_scan_cache_path_for_dir(dir: &Path) -> Option<PathBuf> {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    dir.hash(&mut hasher);
+    let filename = format!("{:016x}.bin", hasher.finish());
+
+    process_path(
+        &Some("xdg".to_string()),
+        PathBuf::from("rust-fontconfig/scan-cache").join(filename),
+        false,
+    )
+}
+
+/// Load a `FontScanCache` previously written by `save_cache`, rejecting it outright if its
+/// version tag doesn't match `FontScanCache::CURRENT_VERSION`.
+#[cfg(feature = "cache")]
+fn load_cache(path: &Path) -> Option<FontScanCache> {
+    let data = std::fs::read(path).ok()?;
+    let cache: FontScanCache = bincode::deserialize(&data).ok()?;
+    if cache.version != FontScanCache::CURRENT_VERSION {
+        return None;
+    }
+    Some(cache)
+}
+
+/// Serialize `cache` to `path`, creating any missing parent directories first.
+#[cfg(feature = "cache")]
+fn save_cache(path: &Path, cache: &FontScanCache) {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(data) = bincode::serialize(cache) {
+        let _ = std::fs::write(path, data);
+    }
+}
+
+/// Get a file's modification time (seconds since epoch) and size, for cache staleness checks.
+#[cfg(feature = "cache")]
+fn stat_file_metadata(path: &Path) -> Option<(u64, u64)> {
+    let meta = std::fs::metadata(path).ok()?;
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Some((mtime, meta.len()))
+}
+
+/// Parses an in-memory font (e.g. one bundled via `include_bytes!`) into its patterns,
+/// mirroring `FcParseFont` but reading from already-loaded bytes instead of a file path.
+///
+/// Unlike `FcParseFont`, TrueType/OpenType collections are not expanded into multiple faces
+/// here - only the first font in the file is parsed.
+#[cfg(all(feature = "std", feature = "parsing"))]
+fn FcParseFontBytes(bytes: &[u8], name: &str) -> Option<Vec<(FcPattern, FcFont)>> {
+    use allsorts_subset_browser::{
+        font_data::FontData,
+        post::PostTable,
+        tables::{HeadTable, HheaTable},
+    };
+
+    const FONT_SPECIFIER_NAME_ID: u16 = 4;
+    const FONT_SPECIFIER_FAMILY_ID: u16 = 1;
+
+    let scope = ReadScope::new(bytes);
+    let font_file = scope.read::<FontData<'_>>().ok()?;
+    let provider = font_file.table_provider(0).ok()?;
+
+    let head_data = provider.table_data(tag::HEAD).ok()??.into_owned();
+    let head_table = ReadScope::new(&head_data).read::<HeadTable>().ok()?;
+    let is_bold = head_table.is_bold();
+    let is_italic = head_table.is_italic();
+
+    let os2_data = provider.table_data(tag::OS_2).ok()??;
+    let os2_table = ReadScope::new(&os2_data)
+        .read_dep::<Os2>(os2_data.len())
+        .ok()?;
+
+    let is_oblique = os2_table
+        .fs_selection
+        .contains(allsorts_subset_browser::tables::os2::FsSelection::OBLIQUE);
+    let weight = FcWeight::from_u16(os2_table.us_weight_class);
+    let weight_value = Some(os2_table.us_weight_class);
+    let weight_axis = extract_weight_axis(&provider);
+    let stretch = FcStretch::from_u16(os2_table.us_width_class);
+    let unicode_ranges = extract_unicode_ranges(&os2_table);
+    let cmap_coverage = extract_cmap_coverage(&provider).unwrap_or_default();
+    let script_coverage = extract_script_coverage(&cmap_coverage);
+    let languages =
+        extract_languages_from_codepage_range(os2_table.ul_code_page_range1, os2_table.ul_code_page_range2);
+
+    let mut detected_monospace = None;
+    let mut underline_position = 0i16;
+    let mut underline_thickness = 0i16;
+    if let Some(post_data) = provider.table_data(tag::POST).ok()? {
+        if let Ok(post_table) = ReadScope::new(&post_data).read::<PostTable>() {
+            detected_monospace = Some(post_table.header.is_fixed_pitch != 0);
+            underline_position = post_table.header.underline_position;
+            underline_thickness = post_table.header.underline_thickness;
+        }
+    }
+
+    let metrics = provider
+        .table_data(tag::HHEA)
+        .ok()
+        .flatten()
+        .and_then(|hhea_data| ReadScope::new(&hhea_data).read::<HheaTable>().ok())
+        .map(|hhea_table| FontMetrics {
+            units_per_em: head_table.units_per_em,
+            ascender: hhea_table.ascender,
+            descender: hhea_table.descender,
+            line_gap: hhea_table.line_gap,
+            cap_height: os2_table.s_cap_height.unwrap_or(0),
+            x_height: os2_table.sx_height.unwrap_or(0),
+            underline_position,
+            underline_thickness,
+            average_advance: os2_table.x_avg_char_width,
+        })
+        .unwrap_or_default();
+    let is_monospace = detect_monospace(&provider, &os2_table, detected_monospace).unwrap_or(false);
+    let spacing = detect_spacing(&provider, is_monospace);
+    let (scalable, outline, embedded_bitmap) = detect_outline_tables(&provider);
+
+    let name_data = provider.table_data(tag::NAME).ok()??.into_owned();
+    let family =
+        get_name_string(&name_data, FONT_SPECIFIER_FAMILY_ID).unwrap_or_else(|| name.to_string());
+    let font_name = get_name_string(&name_data, FONT_SPECIFIER_NAME_ID).unwrap_or_else(|| name.to_string());
+    let serif = classify_serif(&os2_table.panose, Some(family.as_str()));
+
+    let pattern = FcPattern {
+        name: Some(font_name),
+        family: Some(family),
+        bold: if is_bold { PatternMatch::True } else { PatternMatch::False },
+        italic: if is_italic { PatternMatch::True } else { PatternMatch::False },
+        oblique: if is_oblique { PatternMatch::True } else { PatternMatch::False },
+        monospace: if is_monospace { PatternMatch::True } else { PatternMatch::False },
+        spacing,
+        serif,
+        condensed: if stretch <= FcStretch::Condensed {
+            PatternMatch::True
+        } else {
+            PatternMatch::False
+        },
+        weight,
+        weight_value,
+        weight_axis,
+        stretch,
+        unicode_ranges,
+        cmap_coverage,
+        script_coverage,
+        metadata: FcFontMetadata {
+            metrics,
+            ..Default::default()
+        },
+        foundry: extract_foundry(&os2_table.ach_vend_id.to_string(), None, None),
+        languages,
+        required_scripts: Vec::new(),
+        scalable,
+        outline,
+        embedded_bitmap,
+        unknown_properties: Vec::new(),
+    };
+
+    let font = FcFont {
+        bytes: bytes.to_vec(),
+        font_index: 0,
+        id: name.to_string(),
+    };
+
+    Some(vec![(pattern, font)])
+}
+
 #[cfg(feature = "std")]
 /// Takes a path & prefix and resolves them to a usable path, or `None` if they're unsupported/unavailable.
 ///
@@ -1960,12 +5599,97 @@ fn process_path(
     }
 }
 
-// Helper function to extract a string from the name table
+/// Finds and decodes a `name` table string for `name_id`, reading the table's raw bytes
+/// directly instead of going through `fontcode_get_name`: that helper hands back only the raw
+/// bytes of whatever record it happens to find, and those bytes are big-endian UTF-16 on the
+/// Windows and "Unicode" platforms (3 and 0) or single-byte Mac Roman on the old Macintosh
+/// platform (1) - treating either as UTF-8 mangles anything outside ASCII. Walking the records
+/// ourselves means we know the winning record's platform/encoding, so among multiple records
+/// for the same `name_id` we prefer a Windows or Unicode-platform one over a Mac Roman one,
+/// matching how real fontconfig/freetype resolve name strings.
 fn get_name_string(name_data: &[u8], name_id: u16) -> Option<String> {
-    fontcode_get_name(name_data, name_id)
-        .ok()
-        .flatten()
-        .map(|name| String::from_utf8_lossy(name.to_bytes()).to_string())
+    // `name` table header: format (u16), count (u16), stringOffset (u16), then `count` fixed
+    // 12-byte NameRecords - see OpenType spec 'name' table.
+    if name_data.len() < 6 {
+        return None;
+    }
+    let count = u16::from_be_bytes([name_data[2], name_data[3]]) as usize;
+    let storage_offset = u16::from_be_bytes([name_data[4], name_data[5]]) as usize;
+
+    let platform_rank = |platform_id: u16| match platform_id {
+        3 | 0 => 0,
+        1 => 1,
+        _ => 2,
+    };
+
+    let mut best: Option<(u16, &[u8])> = None;
+    for i in 0..count {
+        let record_offset = 6 + i * 12;
+        if record_offset + 12 > name_data.len() {
+            break;
+        }
+        let record = &name_data[record_offset..record_offset + 12];
+        let platform_id = u16::from_be_bytes([record[0], record[1]]);
+        let record_name_id = u16::from_be_bytes([record[6], record[7]]);
+        if record_name_id != name_id {
+            continue;
+        }
+        let length = u16::from_be_bytes([record[8], record[9]]) as usize;
+        let offset = u16::from_be_bytes([record[10], record[11]]) as usize;
+        let start = storage_offset + offset;
+        let end = start + length;
+        if end > name_data.len() || length == 0 {
+            continue;
+        }
+        let bytes = &name_data[start..end];
+
+        let is_better = match best {
+            None => true,
+            Some((best_platform, _)) => platform_rank(platform_id) < platform_rank(best_platform),
+        };
+        if is_better {
+            best = Some((platform_id, bytes));
+        }
+    }
+
+    let (platform_id, bytes) = best?;
+    Some(decode_name_table_bytes(platform_id, bytes))
+}
+
+/// Decodes raw `name` table record bytes per the owning record's platform ID, see
+/// `get_name_string`.
+fn decode_name_table_bytes(platform_id: u16, bytes: &[u8]) -> String {
+    match platform_id {
+        // Windows (3) and "Unicode" (0) platforms: big-endian UTF-16.
+        3 | 0 => {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        // Macintosh platform (1): Mac OS Roman, a single-byte encoding matching ASCII below 0x80.
+        1 => decode_mac_roman(bytes),
+        _ => String::from_utf8_lossy(bytes).to_string(),
+    }
+}
+
+/// Decodes Mac OS Roman bytes: 0x00-0x7F is plain ASCII, 0x80-0xFF maps through this fixed table.
+fn decode_mac_roman(bytes: &[u8]) -> String {
+    const HIGH: [char; 128] = [
+        'Ä', 'Å', 'Ç', 'É', 'Ñ', 'Ö', 'Ü', 'á', 'à', 'â', 'ä', 'ã', 'å', 'ç', 'é', 'è', 'ê', 'ë',
+        'í', 'ì', 'î', 'ï', 'ñ', 'ó', 'ò', 'ô', 'ö', 'õ', 'ú', 'ù', 'û', 'ü', '†', '°', '¢', '£',
+        '§', '•', '¶', 'ß', '®', '©', '™', '´', '¨', '≠', 'Æ', 'Ø', '∞', '±', '≤', '≥', '¥', 'µ',
+        '∂', '∑', '∏', 'π', '∫', 'ª', 'º', 'Ω', 'æ', 'ø', '¿', '¡', '¬', '√', 'ƒ', '≈', '∆', '«',
+        '»', '…', '\u{00A0}', 'À', 'Ã', 'Õ', 'Œ', 'œ', '–', '—', '“', '”', '‘', '’', '÷', '◊',
+        'ÿ', 'Ÿ', '⁄', '€', '‹', '›', 'ﬁ', 'ﬂ', '‡', '·', '‚', '„', '‰', 'Â', 'Ê', 'Á', 'Ë', 'È',
+        'Í', 'Î', 'Ï', 'Ì', 'Ó', 'Ô', '\u{F8FF}', 'Ò', 'Ú', 'Û', 'Ù', 'ı', 'ˆ', '˜', '¯', '˘', '˙',
+        '˚', '¸', '˝', '˛', 'ˇ',
+    ];
+    bytes
+        .iter()
+        .map(|&b| if b < 0x80 { b as char } else { HIGH[(b - 0x80) as usize] })
+        .collect()
 }
 
 // Helper function to extract unicode ranges
@@ -2008,6 +5732,315 @@ fn extract_unicode_ranges(os2_table: &Os2) -> Vec<UnicodeRange> {
     unicode_ranges
 }
 
+/// Reads the `fvar` table's `wght` axis, if the face has one, as `(min, default, max)` in OS/2
+/// `usWeightClass` units - see `FcPattern::weight_axis`. `fvar` has no typed reader in
+/// `allsorts_subset_browser`, so this walks the raw bytes per the OpenType spec: a header
+/// (`axesArrayOffset`/`axisCount`/`axisSize` at fixed offsets) followed by a
+/// `VariationAxisRecord` array, each record a 4-byte axis tag plus three 16.16 fixed-point
+/// `Fixed` values (min/default/max). `None` for a static face or a truncated/malformed table.
+fn extract_weight_axis(provider: &impl FontTableProvider) -> Option<(u16, u16, u16)> {
+    const WGHT_TAG: &[u8; 4] = b"wght";
+
+    let fvar_data = provider.table_data(tag::FVAR).ok()??;
+    let data = &fvar_data[..];
+    if data.len() < 16 {
+        return None;
+    }
+
+    let axes_array_offset = u16::from_be_bytes([data[4], data[5]]) as usize;
+    let axis_count = u16::from_be_bytes([data[8], data[9]]) as usize;
+    let axis_size = u16::from_be_bytes([data[10], data[11]]) as usize;
+    if axis_size < 20 {
+        return None;
+    }
+
+    let read_fixed_as_weight = |offset: usize| -> u16 {
+        let fixed = i32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]);
+        (fixed as f64 / 65536.0).round().clamp(0.0, u16::MAX as f64) as u16
+    };
+
+    for i in 0..axis_count {
+        let offset = axes_array_offset + i * axis_size;
+        if offset + 20 > data.len() {
+            break;
+        }
+        if &data[offset..offset + 4] != WGHT_TAG {
+            continue;
+        }
+
+        let min = read_fixed_as_weight(offset + 4);
+        let default = read_fixed_as_weight(offset + 8);
+        let max = read_fixed_as_weight(offset + 12);
+        return Some((min, default, max));
+    }
+
+    None
+}
+
+/// Classifies a face as serif/sans-serif for `FcPattern::serif`, first from the OS/2 PANOSE
+/// `bSerifStyle` byte (`panose[1]`, valid only when `panose[0]` - `bFamilyType` - is `2`,
+/// "Latin Text"): values `2..=10` are serif strokes, `11..=15` are sans-serif strokes, `0`
+/// ("Any") and `1` ("No Fit") classify nothing. Falls back to a small built-in list of common
+/// family-name substrings for faces that don't carry a meaningful PANOSE (many subset/icon/
+/// symbol fonts zero it out). `DontCare` if neither source classifies the face.
+fn classify_serif(panose: &[u8; 10], family_name: Option<&str>) -> PatternMatch {
+    if panose[0] == 2 {
+        match panose[1] {
+            2..=10 => return PatternMatch::True,
+            11..=15 => return PatternMatch::False,
+            _ => {}
+        }
+    }
+
+    const SERIF_HINTS: &[&str] = &[
+        "serif", "times", "georgia", "garamond", "cambria", "palatino", "minion", "caslon",
+        "baskerville", "book antiqua", "constantia", "cardo", "pt serif", "source serif",
+        "merriweather", "playfair",
+    ];
+    const SANS_HINTS: &[&str] = &[
+        "sans", "arial", "helvetica", "verdana", "tahoma", "segoe ui", "calibri", "roboto",
+        "ubuntu", "open sans", "droid sans", "noto sans", "liberation sans", "ms sans serif",
+        "trebuchet",
+    ];
+
+    let Some(family) = family_name else {
+        return PatternMatch::DontCare;
+    };
+    let lower = family.to_lowercase();
+
+    // "sans-serif"/"sans serif" contains the bare "serif" substring that SERIF_HINTS matches
+    // on, so it has to be special-cased ahead of the generic hint scan or e.g. "Sans Serif
+    // Collection" would be misclassified as a serif family.
+    if lower.contains("sans-serif") || lower.contains("sans serif") {
+        PatternMatch::False
+    } else if SERIF_HINTS.iter().any(|hint| lower.contains(hint)) {
+        PatternMatch::True
+    } else if SANS_HINTS.iter().any(|hint| lower.contains(hint)) {
+        PatternMatch::False
+    } else {
+        PatternMatch::DontCare
+    }
+}
+
+/// Walks the font's actual `cmap` table and returns the codepoints it maps to a glyph, as a
+/// sorted, merged run-length list of ranges - the real per-glyph coverage `contains_char`
+/// prefers over the coarse OS/2 block hints `extract_unicode_ranges` produces above, which
+/// only say a font "touches" a block, not that every codepoint in it resolves to a glyph.
+/// Returns `None` if the `cmap` table is missing or none of its subtables parse.
+fn extract_cmap_coverage(provider: &impl FontTableProvider) -> Option<Vec<UnicodeRange>> {
+    use allsorts_subset_browser::cmap::{Cmap, CmapSubtable};
+
+    let cmap_data = provider.table_data(tag::CMAP).ok()??;
+    let cmap = ReadScope::new(&cmap_data).read::<Cmap<'_>>().ok()?;
+
+    // Prefer a Unicode-aware subtable: full repertoire (3,10 or 0,4/0,6), then BMP-only
+    // (3,1 or 0,3), then legacy Mac Roman (1,0) as a last resort.
+    const PREFERRED_ENCODINGS: &[(u16, u16)] =
+        &[(3, 10), (0, 4), (0, 6), (3, 1), (0, 3), (0, 2), (0, 1), (0, 0), (1, 0)];
+
+    let mut subtable = None;
+    for &(platform_id, encoding_id) in PREFERRED_ENCODINGS {
+        if let Ok(Some(found)) = cmap.find_subtable(platform_id, encoding_id) {
+            subtable = Some(found);
+            break;
+        }
+    }
+    let subtable = subtable?;
+
+    let mut ranges = Vec::new();
+    match subtable {
+        CmapSubtable::Format12 { groups, .. } => {
+            for group in groups.iter() {
+                ranges.push(UnicodeRange {
+                    start: group.start_char_code,
+                    end: group.end_char_code,
+                });
+            }
+        }
+        CmapSubtable::Format4 {
+            start_codes,
+            end_codes,
+            ..
+        } => {
+            for i in 0..start_codes.len() {
+                let start = match start_codes.read_item(i) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+                let end = match end_codes.read_item(i) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+                // 0xFFFF is the mandatory sentinel segment terminating the format-4 table.
+                if start == 0xFFFF && end == 0xFFFF {
+                    continue;
+                }
+                ranges.push(UnicodeRange {
+                    start: start as u32,
+                    end: end as u32,
+                });
+            }
+        }
+        CmapSubtable::Format6 {
+            first_code,
+            glyph_id_array,
+            ..
+        } => {
+            for (offset, &glyph_id) in glyph_id_array.iter().enumerate() {
+                if glyph_id != 0 {
+                    let codepoint = first_code as u32 + offset as u32;
+                    ranges.push(UnicodeRange {
+                        start: codepoint,
+                        end: codepoint,
+                    });
+                }
+            }
+        }
+        CmapSubtable::Format0 { glyph_id_array } => {
+            for (codepoint, &glyph_id) in glyph_id_array.iter().enumerate() {
+                if glyph_id != 0 {
+                    ranges.push(UnicodeRange {
+                        start: codepoint as u32,
+                        end: codepoint as u32,
+                    });
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    if ranges.is_empty() {
+        return None;
+    }
+
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<UnicodeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    Some(merged)
+}
+
+/// Derives a rough set of supported BCP-47 language tags from the OS/2 `ulCodePageRange1/2`
+/// bitfields. This is intentionally a simplified subset of the common codepages rather than an
+/// exhaustive mapping of every bit in the OpenType spec.
+fn extract_languages_from_codepage_range(range1: u32, range2: u32) -> Vec<String> {
+    // (bit index within range1, language tag)
+    const RANGE1_LANGUAGES: &[(u32, &str)] = &[
+        (0, "en"),   // Latin 1
+        (1, "pl"),   // Latin 2: Eastern Europe
+        (2, "ru"),   // Cyrillic
+        (3, "el"),   // Greek
+        (4, "tr"),   // Turkish
+        (5, "he"),   // Hebrew
+        (6, "ar"),   // Arabic
+        (7, "sv"),   // Windows Baltic
+        (8, "vi"),   // Vietnamese
+        (17, "ja"),  // JIS/Japan
+        (18, "zh-Hans"), // Chinese: Simplified
+        (19, "ko"),  // Korean Wansung
+        (20, "zh-Hant"), // Chinese: Traditional
+        (21, "ko"),  // Korean Johab
+    ];
+
+    let mut languages = Vec::new();
+
+    for &(bit, tag) in RANGE1_LANGUAGES {
+        if range1 & (1 << bit) != 0 && !languages.contains(&tag.to_string()) {
+            languages.push(tag.to_string());
+        }
+    }
+
+    // Symbol/OEM bits in range2 don't map to a natural language, so they're left unmapped
+    let _ = range2;
+
+    languages
+}
+
+/// Derives a font's foundry, preferring the OS/2 `achVendID` tag (trimmed of padding) and
+/// falling back to scanning `copyright`/`trademark` notices for known foundry substrings - see
+/// `FcPattern::foundry`. The notice table is checked in order and the first match wins, since
+/// some notices name more than one foundry (e.g. a URW-hinted B&H font).
+fn extract_foundry(ach_vend_id: &str, copyright: Option<&str>, trademark: Option<&str>) -> Option<String> {
+    let vend_id = ach_vend_id.trim();
+    if !vend_id.is_empty() && vend_id.bytes().any(|b| b != 0) {
+        return Some(vend_id.to_string());
+    }
+
+    const NOTICE_FOUNDRIES: &[(&str, &str)] = &[
+        ("Bigelow", "b&h"),
+        ("Adobe", "adobe"),
+        ("Bitstream", "bitstream"),
+        ("Monotype", "monotype"),
+        ("Linotype", "linotype"),
+        ("LINOTYPE-HELL", "linotype"),
+        ("URW", "urw"),
+        ("IBM", "ibm"),
+        ("B&H", "b&h"),
+    ];
+
+    for notice in [copyright, trademark].into_iter().flatten() {
+        for (needle, foundry) in NOTICE_FOUNDRIES {
+            if notice.contains(needle) {
+                return Some((*foundry).to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// Coarse Unicode-block -> OpenType script tag table for `extract_script_coverage`. Not an
+/// exhaustive mapping of every script in the standard - just the common ones a mixed-script
+/// fallback chain (Latin + CJK + Arabic + ...) is likely to need `fonts_for_script` for.
+const SCRIPT_BLOCKS: &[(u32, u32, [u8; 4])] = &[
+    (0x0000, 0x024F, *b"latn"), // Basic Latin, Latin-1 Supplement, Latin Extended A/B
+    (0x0370, 0x03FF, *b"grek"), // Greek and Coptic
+    (0x0400, 0x04FF, *b"cyrl"), // Cyrillic
+    (0x0530, 0x058F, *b"armn"), // Armenian
+    (0x0590, 0x05FF, *b"hebr"), // Hebrew
+    (0x0600, 0x06FF, *b"arab"), // Arabic
+    (0x0900, 0x097F, *b"deva"), // Devanagari
+    (0x0E00, 0x0E7F, *b"thai"), // Thai
+    (0x10A0, 0x10FF, *b"geor"), // Georgian
+    (0x3040, 0x309F, *b"hira"), // Hiragana
+    (0x30A0, 0x30FF, *b"kana"), // Katakana
+    (0x3400, 0x4DBF, *b"hani"), // CJK Unified Ideographs Extension A
+    (0x4E00, 0x9FFF, *b"hani"), // CJK Unified Ideographs
+    (0xAC00, 0xD7AF, *b"hang"), // Hangul Syllables
+];
+
+/// Derives the OpenType script tags a font's `cmap_coverage` touches, by intersecting it
+/// against `SCRIPT_BLOCKS`. Stored on `FcPattern::script_coverage` at scan time so
+/// `FcFontCache::fonts_for_script` and `required_scripts` matching can check script support
+/// without re-deriving it (or probing codepoints) on every query.
+fn extract_script_coverage(cmap_coverage: &[UnicodeRange]) -> Vec<[u8; 4]> {
+    let mut scripts = Vec::new();
+
+    for &(start, end, tag) in SCRIPT_BLOCKS {
+        let in_script = cmap_coverage
+            .iter()
+            .any(|range| range.start <= end && range.end >= start);
+
+        if in_script && !scripts.contains(&tag) {
+            scripts.push(tag);
+        }
+    }
+
+    scripts
+}
+
 // Helper function to detect if a font is monospace
 fn detect_monospace(
     provider: &impl FontTableProvider,
@@ -2052,3 +6085,72 @@ fn detect_monospace(
 
     Some(monospace)
 }
+
+/// Classifies a font's spacing level beyond the simple monospace boolean, per
+/// fontconfig's `FC_SPACING`.
+fn detect_spacing(provider: &impl FontTableProvider, is_monospace: bool) -> FcSpacing {
+    if !is_monospace {
+        // Distinguish "proportional" from "dual" (two dominant widths, as in half/full-width
+        // CJK faces) by sampling the hmtx table.
+        if let Some(widths) = collect_hmtx_widths(provider) {
+            let distinct: BTreeSet<u16> = widths.into_iter().collect();
+            if distinct.len() == 2 {
+                return FcSpacing::Dual;
+            }
+        }
+        return FcSpacing::Proportional;
+    }
+
+    // `CharCell` (guaranteed uniform cell metrics, including box-drawing glyphs) isn't
+    // distinguished from plain `Mono` by any standard table; callers that need it can still
+    // request it explicitly via `FcPattern::parse`.
+    FcSpacing::Mono
+}
+
+/// Classifies a face as scalable/outline (true vector `glyf` or `CFF ` outlines) vs.
+/// carrying embedded bitmap strikes (`EBDT`/`CBDT`), by checking which tables are present
+/// in the sfnt directory - backs `FcPattern::scalable`/`outline`/`embedded_bitmap`, which
+/// `query_matches_internal` then consults like any other style predicate. Returns
+/// `(scalable, outline, embedded_bitmap)`; `outline` always tracks `scalable` since this
+/// crate doesn't distinguish the two concepts (see the field doc comments).
+fn detect_outline_tables(
+    provider: &impl FontTableProvider,
+) -> (PatternMatch, PatternMatch, PatternMatch) {
+    let has_outlines = provider.table_data(tag::GLYF).ok().flatten().is_some()
+        || provider.table_data(tag::CFF).ok().flatten().is_some();
+    let has_bitmaps = provider.table_data(tag::EBDT).ok().flatten().is_some()
+        || provider.table_data(tag::CBDT).ok().flatten().is_some();
+
+    let scalable = if has_outlines {
+        PatternMatch::True
+    } else {
+        PatternMatch::False
+    };
+    let embedded_bitmap = if has_bitmaps {
+        PatternMatch::True
+    } else {
+        PatternMatch::False
+    };
+
+    (scalable, scalable, embedded_bitmap)
+}
+
+fn collect_hmtx_widths(provider: &impl FontTableProvider) -> Option<Vec<u16>> {
+    let hhea_data = provider.table_data(tag::HHEA).ok()??;
+    let hhea_table = ReadScope::new(&hhea_data).read::<HheaTable>().ok()?;
+    let maxp_data = provider.table_data(tag::MAXP).ok()??;
+    let maxp_table = ReadScope::new(&maxp_data).read::<MaxpTable>().ok()?;
+    let hmtx_data = provider.table_data(tag::HMTX).ok()??;
+    let hmtx_table = ReadScope::new(&hmtx_data)
+        .read_dep::<HmtxTable<'_>>((
+            usize::from(maxp_table.num_glyphs),
+            usize::from(hhea_table.num_h_metrics),
+        ))
+        .ok()?;
+
+    let mut widths = Vec::with_capacity(hhea_table.num_h_metrics as usize);
+    for i in 0..hhea_table.num_h_metrics as usize {
+        widths.push(hmtx_table.h_metrics.read_item(i).ok()?.advance_width);
+    }
+    Some(widths)
+}
@@ -38,17 +38,18 @@ use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
+use core::ops::Range;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
 use crate::{
-    CssFallbackGroup, FcFont, FcFontCache, FcFontPath, FcPattern,
-    FcWeight, FontChainCacheKey, FontFallbackChain, FontId, FontMatch, NamedFont, OperatingSystem,
-    PatternMatch,
+    CssFallbackGroup, FallbackMetricOverrides, FcFont, FcFontCache, FcFontPath, FcPattern,
+    FcSpacing, FcWeight, FontChainCacheKey, FontFallbackChain, FontId, FontMatch, FontMetrics,
+    FontSynthesis, MatchReason, NamedFont, OperatingSystem, PatternMatch, TraceLevel, TraceMsg,
 };
 
 // ── Priority Queue ──────────────────────────────────────────────────────────
@@ -68,7 +69,7 @@ pub enum Priority {
 }
 
 /// A job for the Builder pool to process.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FcBuildJob {
     pub priority: Priority,
     pub path: PathBuf,
@@ -77,22 +78,247 @@ pub struct FcBuildJob {
     pub guessed_family: String,
 }
 
-impl PartialEq for FcBuildJob {
-    fn eq(&self, other: &Self) -> bool {
-        self.priority == other.priority && self.path == other.path
+/// Lock-free work distribution for the Builder pool: one unbounded channel lane per
+/// `Priority`, so pushing a Critical job never contends with (or needs to re-sort past)
+/// the Low lane the Scout is still filling. Builders drain Critical → High → Medium →
+/// Low so a blocked `request_fonts` call always jumps the rest of the backlog.
+struct BuildQueue {
+    critical: (
+        crossbeam_channel::Sender<FcBuildJob>,
+        crossbeam_channel::Receiver<FcBuildJob>,
+    ),
+    high: (
+        crossbeam_channel::Sender<FcBuildJob>,
+        crossbeam_channel::Receiver<FcBuildJob>,
+    ),
+    medium: (
+        crossbeam_channel::Sender<FcBuildJob>,
+        crossbeam_channel::Receiver<FcBuildJob>,
+    ),
+    low: (
+        crossbeam_channel::Sender<FcBuildJob>,
+        crossbeam_channel::Receiver<FcBuildJob>,
+    ),
+}
+
+impl BuildQueue {
+    fn new() -> Self {
+        Self {
+            critical: crossbeam_channel::unbounded(),
+            high: crossbeam_channel::unbounded(),
+            medium: crossbeam_channel::unbounded(),
+            low: crossbeam_channel::unbounded(),
+        }
+    }
+
+    fn lane(&self, priority: Priority) -> &crossbeam_channel::Sender<FcBuildJob> {
+        match priority {
+            Priority::Critical => &self.critical.0,
+            Priority::High => &self.high.0,
+            Priority::Medium => &self.medium.0,
+            Priority::Low => &self.low.0,
+        }
+    }
+
+    /// Push a job onto its priority's lane. Never blocks and never touches a global lock.
+    fn push(&self, job: FcBuildJob) {
+        // An unbounded channel send only fails once every receiver is dropped, which
+        // can't happen while `self` (and thus the Builder pool's receivers) is alive.
+        let _ = self.lane(job.priority).send(job);
+    }
+
+    /// Pop the highest-priority job available without blocking.
+    fn try_pop(&self) -> Option<FcBuildJob> {
+        self.critical
+            .1
+            .try_recv()
+            .or_else(|_| self.high.1.try_recv())
+            .or_else(|_| self.medium.1.try_recv())
+            .or_else(|_| self.low.1.try_recv())
+            .ok()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.critical.1.is_empty()
+            && self.high.1.is_empty()
+            && self.medium.1.is_empty()
+            && self.low.1.is_empty()
     }
 }
-impl Eq for FcBuildJob {}
 
-impl PartialOrd for FcBuildJob {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+/// A shared park/unpark point for idle Builder threads, replacing a `Condvar` so a push
+/// onto any `BuildQueue` lane can wake a waiter without taking a lock. Each waiter parks
+/// with a short timeout as a safety net in case a wake is missed (e.g. the thread wasn't
+/// registered yet when `wake_all` ran).
+struct Parker {
+    waiters: Mutex<Vec<std::thread::Thread>>,
+}
+
+impl Parker {
+    fn new() -> Self {
+        Self {
+            waiters: Mutex::new(Vec::new()),
+        }
     }
+
+    /// Register the calling thread as a waiter, then park it until woken or `timeout`
+    /// elapses.
+    fn park_timeout(&self, timeout: Duration) {
+        self.waiters.lock().unwrap().push(std::thread::current());
+        std::thread::park_timeout(timeout);
+    }
+
+    /// Wake every thread currently parked.
+    fn wake_all(&self) {
+        for waiter in self.waiters.lock().unwrap().drain(..) {
+            waiter.unpark();
+        }
+    }
+}
+
+// ── Per-Style Family Overrides ──────────────────────────────────────────────
+
+/// Distinct `font-family` stacks for each style slot, so bold/italic text can be rendered
+/// with a different family than the regular face instead of always synthesizing a style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleFamilies {
+    /// Family stack used for the regular (upright, non-bold) face
+    pub regular: Vec<String>,
+    /// Family stack used for the bold face, if distinct from `regular`
+    pub bold: Option<Vec<String>>,
+    /// Family stack used for the italic face, if distinct from `regular`
+    pub italic: Option<Vec<String>>,
+    /// Family stack used for the bold-italic face, if distinct from `regular`/`bold`/`italic`
+    pub bold_italic: Option<Vec<String>>,
+}
+
+impl StyleFamilies {
+    /// The common case: resolve every style slot from the same `font-family` stack, with
+    /// synthetic weight/slant selection for bold/italic as `resolve_font_chain` already does.
+    /// Equivalent to `StyleFamilies { regular: families, ..Default::default() }`, for callers
+    /// that don't need a style-specific override.
+    pub fn uniform(families: Vec<String>) -> Self {
+        StyleFamilies {
+            regular: families,
+            ..Default::default()
+        }
+    }
+}
+
+/// The four resolved chains for a `StyleFamilies` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerStyleFontChain {
+    pub regular: FontFallbackChain,
+    pub bold: FontFallbackChain,
+    pub italic: FontFallbackChain,
+    pub bold_italic: FontFallbackChain,
+}
+
+impl PerStyleFontChain {
+    /// Picks the chain matching the requested `weight`/`italic`/`oblique` attributes, the
+    /// same bold/italic split `resolve_font_chain_per_style` resolved `StyleFamilies` into.
+    /// Bold is `weight >= FcWeight::Bold`, matching CSS's own `700` cutoff; italic/oblique
+    /// are treated as equivalent, the same way `FontSynthesis::compute` does.
+    pub fn select(
+        &self,
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+    ) -> &FontFallbackChain {
+        let is_bold = weight >= FcWeight::Bold;
+        let is_italic = italic == PatternMatch::True || oblique == PatternMatch::True;
+        match (is_bold, is_italic) {
+            (true, true) => &self.bold_italic,
+            (true, false) => &self.bold,
+            (false, true) => &self.italic,
+            (false, false) => &self.regular,
+        }
+    }
+}
+
+// ── Chain Diagnostics (`explain_chain`) ─────────────────────────────────────
+
+/// Where a resolved font's bytes ultimately come from, for `explain_chain` diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontOrigin {
+    /// Registered via `register_memory_fonts`; no on-disk path.
+    Memory,
+    /// Parsed from a disk file this run.
+    Disk(FcFontPath),
+    /// Trusted from a `load_manifest` disk-cache hit rather than freshly parsed this run.
+    DiskCache(FcFontPath),
+}
+
+impl std::fmt::Display for FontOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontOrigin::Memory => write!(f, "memory"),
+            FontOrigin::Disk(path) => write!(f, "disk:{}", path.path),
+            FontOrigin::DiskCache(path) => write!(f, "disk-cache:{}", path.path),
+        }
+    }
+}
+
+/// One candidate face considered while resolving a single family, carrying the scoring
+/// and trace data `resolve_font_chain`'s internals compute but normally discard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedCandidate {
+    /// The matched face's full metadata pattern.
+    pub pattern: FcPattern,
+    /// Where this face's bytes come from.
+    pub origin: FontOrigin,
+    /// Percentage of the requested family's name tokens this face's tokens matched.
+    /// `None` for generic families (`serif`/`monospace`/...), which match by pattern
+    /// rather than by name.
+    pub token_similarity: Option<i32>,
+    /// Style closeness to the requested weight/stretch/slant; lower is a closer match.
+    pub style_score: i32,
+    /// `query_matches_internal` trace for this face against the requested style.
+    pub trace: Vec<TraceMsg>,
+}
+
+/// One requested family's resolved candidates, in the order `resolve_font_chain` picks them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedFamily {
+    /// The (possibly generic) family name this group was resolved from.
+    pub css_name: String,
+    /// Candidate faces, best match first.
+    pub candidates: Vec<ExplainedCandidate>,
 }
 
-impl Ord for FcBuildJob {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.priority.cmp(&other.priority)
+/// Diagnostic report from `FcFontRegistry::explain_chain`, explaining why a CSS `font-family`
+/// stack resolved the way it did — akin to wezterm's `ls-fonts` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainExplanation {
+    pub families: Vec<ExplainedFamily>,
+}
+
+impl std::fmt::Display for ChainExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for family in &self.families {
+            writeln!(f, "family: {}", family.css_name)?;
+            if family.candidates.is_empty() {
+                writeln!(f, "  (no candidates found)")?;
+                continue;
+            }
+            for (i, candidate) in family.candidates.iter().enumerate() {
+                let name = candidate
+                    .pattern
+                    .name
+                    .as_deref()
+                    .or(candidate.pattern.family.as_deref())
+                    .unwrap_or("<unknown>");
+                write!(f, "  {}. {} [{}]", i + 1, name, candidate.origin)?;
+                if let Some(similarity) = candidate.token_similarity {
+                    write!(f, " token_similarity={}%", similarity)?;
+                }
+                writeln!(f, " style_score={}", candidate.style_score)?;
+                for msg in &candidate.trace {
+                    writeln!(f, "       - {:?}: {:?}", msg.level, msg.reason)?;
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -107,6 +333,31 @@ struct FontRequest {
     satisfied: Arc<AtomicBool>,
 }
 
+// ── Memory-Mapped Font Bytes ────────────────────────────────────────────────
+
+/// A zero-copy (where the platform allows it) view of a font file's bytes.
+///
+/// Disk fonts are served through a cached `mmap`, so repeatedly requested faces don't
+/// re-read or re-map; in-memory fonts and any file that fails to map are served from an
+/// owned buffer instead. Either way, callers should go through `Deref<Target = [u8]>`.
+pub enum MappedFontBytes {
+    #[cfg(feature = "mmap")]
+    Mapped(Arc<memmap2::Mmap>),
+    Owned(Arc<Vec<u8>>),
+}
+
+impl std::ops::Deref for MappedFontBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "mmap")]
+            MappedFontBytes::Mapped(mmap) => &mmap[..],
+            MappedFontBytes::Owned(bytes) => &bytes[..],
+        }
+    }
+}
+
 // ── The Registry ────────────────────────────────────────────────────────────
 
 /// Thread-safe, incrementally-populated font registry.
@@ -131,16 +382,38 @@ pub struct FcFontRegistry {
     token_index: RwLock<BTreeMap<String, BTreeSet<FontId>>>,
     /// FontId → pre-tokenized lowercase name tokens
     font_tokens: RwLock<BTreeMap<FontId, Vec<String>>>,
+    /// Inverted index from a Unicode range's *start* codepoint to the fonts that declare
+    /// a range beginning there. A lookup for codepoint `c` scans `range(..=c)` (picking up
+    /// every font with a candidate range) and then double-checks each candidate's actual
+    /// ranges via `metadata` to confirm `end >= c`, since a range starting before `c` may
+    /// still end before it. See `fonts_covering`.
+    coverage_index: RwLock<BTreeMap<u32, BTreeSet<FontId>>>,
 
     // ── In-memory fonts (bundled, embedded) ──
     memory_fonts: RwLock<BTreeMap<FontId, FcFont>>,
 
+    /// Cache of open `mmap`s for disk fonts, keyed by `FontId`. Holds only a `Weak`
+    /// reference, so a mapping is dropped (and the fd/VMA reclaimed) once the last
+    /// `MappedFontBytes` handle referencing it goes away; a still-alive entry is
+    /// reused instead of re-mapping the file. Used as a fallback for faces that weren't
+    /// mapped eagerly at build time, e.g. ones restored from `load_manifest`.
+    #[cfg(feature = "mmap")]
+    mmap_cache: Mutex<HashMap<FontId, std::sync::Weak<memmap2::Mmap>>>,
+
+    /// Every disk font file the Builder thread has memory-mapped during parsing, keyed
+    /// by file path rather than `FontId` so a multi-face `.ttc` is opened and mapped
+    /// exactly once and every face it yields shares that one mapping — no second `open`
+    /// when a rasterizer later calls `get_font_bytes` for each face. Entries stay mapped
+    /// for the registry's lifetime (unlike `mmap_cache`'s `Weak` references).
+    #[cfg(feature = "mmap")]
+    mapped_fonts: RwLock<HashMap<String, Arc<memmap2::Mmap>>>,
+
     // ── Chain cache (computed lazily) ──
     chain_cache: Mutex<HashMap<FontChainCacheKey, FontFallbackChain>>,
 
     // ── Priority queue for Builder ──
-    build_queue: Mutex<Vec<FcBuildJob>>,
-    queue_condvar: Condvar,
+    build_queue: BuildQueue,
+    parker: Parker,
 
     // ── Completion tracking ──
     pending_requests: Mutex<Vec<FontRequest>>,
@@ -164,6 +437,29 @@ pub struct FcFontRegistry {
 
     // ── Operating system (for font family expansion) ──
     os: OperatingSystem,
+
+    /// Generic family → ordered list of user-preferred concrete family names. Populated from
+    /// `<alias>`/`<default>` blocks in `/etc/fonts/fonts.conf` (Linux only) and from explicit
+    /// `register_generic_alias` calls; either merges into rather than replaces the other, so
+    /// neither source wipes out generics the other didn't mention. Read by
+    /// `resolve_font_chain_uncached` (as the highest-priority match for a literal generic
+    /// family) and fed into `expand_font_families` as `extra_aliases` (taking precedence over
+    /// that function's own built-in OS defaults). Empty until fonts.conf is parsed or an alias
+    /// is registered.
+    generic_aliases: RwLock<BTreeMap<String, Vec<String>>>,
+
+    /// Last known `(mtime_secs, file_size)` for every loaded disk font's source file,
+    /// keyed by path (a `.ttc` yields multiple `FontId`s sharing one entry here). Used by
+    /// the optional `feature = "watch"` filesystem watcher to tell a genuine edit from a
+    /// spurious event, without re-reading every file on every notification.
+    #[cfg(feature = "watch")]
+    file_metadata: RwLock<HashMap<String, (u64, u64)>>,
+
+    /// `FontId`s that were trusted straight from a `load_manifest` disk-cache hit rather
+    /// than freshly parsed this run. Only used to distinguish `FontOrigin::DiskCache` from
+    /// `FontOrigin::Disk` in `explain_chain`'s diagnostics.
+    #[cfg(feature = "cache")]
+    cache_loaded_ids: Mutex<HashSet<FontId>>,
 }
 
 impl std::fmt::Debug for FcFontRegistry {
@@ -189,10 +485,15 @@ impl FcFontRegistry {
             metadata: RwLock::new(BTreeMap::new()),
             token_index: RwLock::new(BTreeMap::new()),
             font_tokens: RwLock::new(BTreeMap::new()),
+            coverage_index: RwLock::new(BTreeMap::new()),
             memory_fonts: RwLock::new(BTreeMap::new()),
+            #[cfg(feature = "mmap")]
+            mmap_cache: Mutex::new(HashMap::new()),
+            #[cfg(feature = "mmap")]
+            mapped_fonts: RwLock::new(HashMap::new()),
             chain_cache: Mutex::new(HashMap::new()),
-            build_queue: Mutex::new(Vec::new()),
-            queue_condvar: Condvar::new(),
+            build_queue: BuildQueue::new(),
+            parker: Parker::new(),
             pending_requests: Mutex::new(Vec::new()),
             request_complete: Condvar::new(),
             processed_paths: Mutex::new(HashSet::new()),
@@ -204,6 +505,11 @@ impl FcFontRegistry {
             faces_loaded: AtomicUsize::new(0),
             files_discovered: AtomicUsize::new(0),
             os: OperatingSystem::current(),
+            generic_aliases: RwLock::new(BTreeMap::new()),
+            #[cfg(feature = "watch")]
+            file_metadata: RwLock::new(HashMap::new()),
+            #[cfg(feature = "cache")]
+            cache_loaded_ids: Mutex::new(HashSet::new()),
         })
     }
 
@@ -216,6 +522,7 @@ impl FcFontRegistry {
                 let mut memory_fonts = self.memory_fonts.write().unwrap();
                 let mut token_index = self.token_index.write().unwrap();
                 let mut font_tokens = self.font_tokens.write().unwrap();
+                let mut coverage_index = self.coverage_index.write().unwrap();
 
                 for (pattern, fc_font) in parsed {
                     let id = FontId::new();
@@ -225,6 +532,7 @@ impl FcFontRegistry {
                         &pattern,
                         id,
                     );
+                    Self::index_coverage_static(&mut coverage_index, &pattern, id);
                     patterns.insert(pattern.clone(), id);
                     metadata.insert(id, pattern);
                     memory_fonts.insert(id, fc_font);
@@ -233,6 +541,36 @@ impl FcFontRegistry {
         }
     }
 
+    /// Register or override the ordered list of concrete families a generic CSS family name
+    /// (`serif`, `sans-serif`, `monospace`, `cursive`, `fantasy`, ...) resolves through, the
+    /// way fontconfig's `<alias>`/`<default>` rules do. An entry registered here takes
+    /// precedence over `expand_font_families`'s built-in OS-appropriate defaults for the same
+    /// name, and merges with whatever `/etc/fonts/fonts.conf` contributed on Linux (see
+    /// `scout_thread`) rather than replacing it. Takes effect for subsequent `request_fonts`,
+    /// `resolve_font_chain`, and `explain_chain` calls — already-cached chains are dropped so
+    /// they get re-resolved under the new alias.
+    pub fn register_generic_alias(&self, generic_family: &str, preferred_families: Vec<String>) {
+        self.generic_aliases
+            .write()
+            .unwrap()
+            .insert(generic_family.to_lowercase(), preferred_families);
+
+        if let Ok(mut cache) = self.chain_cache.lock() {
+            cache.clear();
+        }
+    }
+
+    /// Snapshot of `generic_aliases` in the `(name, preferred_families)` shape
+    /// `expand_font_families` expects for its `extra_aliases` parameter.
+    fn generic_alias_overrides(&self) -> Vec<(String, Vec<String>)> {
+        self.generic_aliases
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
     /// Spawn the Scout thread and Builder pool. Returns immediately.
     pub fn spawn_scout_and_builders(self: &Arc<Self>) {
         let num_threads = std::thread::available_parallelism()
@@ -276,26 +614,77 @@ impl FcFontRegistry {
     ) -> Vec<FontFallbackChain> {
         let deadline = Instant::now() + Duration::from_secs(5);
 
-        // 1. Expand generic families and collect all unique family names we need
-        let mut needed_families: Vec<String> = Vec::new();
-        let mut expanded_stacks: Vec<Vec<String>> = Vec::new();
+        let extra_aliases = self.generic_alias_overrides();
+        let expanded_stacks: Vec<Vec<String>> = family_stacks
+            .iter()
+            .map(|stack| crate::expand_font_families(stack, self.os, &extra_aliases))
+            .collect();
+
+        self.block_until_families_ready(&expanded_stacks, deadline);
+        self.resolve_chains(&expanded_stacks)
+    }
+
+    /// Like `request_fonts`, but lets each requested entry carry a distinct family stack
+    /// per style slot (`StyleFamilies`) instead of one stack applied uniformly to every
+    /// style. This is what lets a terminal/editor say "JetBrains Mono for regular text,
+    /// but Fira Code for italic" and have *both* families prioritized and waited on up
+    /// front, rather than only discovering the italic family is missing once
+    /// `resolve_font_chain_per_style` resolves it later (mirrors Alacritty's font config,
+    /// where roman/bold/italic may come from entirely different families).
+    ///
+    /// Hard timeout: 5 seconds, shared across every requested entry.
+    pub fn request_styled_fonts(&self, requests: &[StyleFamilies]) -> Vec<PerStyleFontChain> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+
+        let extra_aliases = self.generic_alias_overrides();
+        let expanded_stacks: Vec<Vec<String>> = requests
+            .iter()
+            .flat_map(|r| {
+                let bold = r.bold.as_ref().unwrap_or(&r.regular);
+                let italic = r.italic.as_ref().unwrap_or(&r.regular);
+                let bold_italic = r
+                    .bold_italic
+                    .as_ref()
+                    .or(r.bold.as_ref())
+                    .or(r.italic.as_ref())
+                    .unwrap_or(&r.regular);
+                [&r.regular, bold, italic, bold_italic]
+                    .into_iter()
+                    .map(|stack| crate::expand_font_families(stack, self.os, &extra_aliases))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        self.block_until_families_ready(&expanded_stacks, deadline);
+
+        requests
+            .iter()
+            .map(|r| self.resolve_font_chain_per_style(r))
+            .collect()
+    }
 
-        for stack in family_stacks {
-            let expanded = crate::expand_font_families(stack, self.os, &[]);
-            for family in &expanded {
+    /// Block until every family name referenced by `expanded_stacks` (already passed
+    /// through `expand_font_families`) is either loaded or confirmed absent, boosting any
+    /// missing ones to `Priority::Critical` in the Builder's queue. Shared by
+    /// `request_fonts` and `request_styled_fonts`; callers resolve chains themselves
+    /// afterwards since the two differ in how they turn stacks back into chains.
+    fn block_until_families_ready(&self, expanded_stacks: &[Vec<String>], deadline: Instant) {
+        // 1. Collect all unique family names we need
+        let mut needed_families: Vec<String> = Vec::new();
+        for stack in expanded_stacks {
+            for family in stack {
                 let normalized = normalize_family_name(family);
                 if !needed_families.contains(&normalized) {
                     needed_families.push(normalized);
                 }
             }
-            expanded_stacks.push(expanded);
         }
 
         // Fast path: if disk cache was loaded, all previously-known fonts are
-        // already in the patterns map.  We can resolve chains immediately.
-        // Background builders will pick up any newly installed fonts later.
+        // already in the patterns map. Background builders will pick up any newly
+        // installed fonts later.
         if self.cache_loaded.load(Ordering::Acquire) {
-            return self.resolve_chains(&expanded_stacks);
+            return;
         }
 
         // 2. Check which families are already in the registry
@@ -319,9 +708,9 @@ impl FcFontRegistry {
             }
         }
 
-        // 3. If nothing is missing, resolve chains immediately
+        // 3. If nothing is missing, there's nothing to wait for
         if missing.is_empty() {
-            return self.resolve_chains(&expanded_stacks);
+            return;
         }
 
         // 4. Wait for Scout to finish (so we can look up file paths)
@@ -331,7 +720,7 @@ impl FcFontRegistry {
                     "[azul-font-registry] WARNING: Timed out waiting for font scout (5s). \
                      Proceeding with available fonts."
                 );
-                return self.resolve_chains(&expanded_stacks);
+                return;
             }
             std::thread::sleep(Duration::from_millis(1));
         }
@@ -339,12 +728,11 @@ impl FcFontRegistry {
         // 5. For each missing family, look up known_paths and push Critical jobs
         {
             let known_paths = self.known_paths.read().unwrap();
-            let mut queue = self.build_queue.lock().unwrap();
 
             for family in &missing {
                 if let Some(paths) = known_paths.get(family) {
                     for path in paths {
-                        queue.push(FcBuildJob {
+                        self.build_queue.push(FcBuildJob {
                             priority: Priority::Critical,
                             path: path.clone(),
                             font_index: None,
@@ -358,7 +746,7 @@ impl FcFontRegistry {
                         || family.contains(known_family.as_str())
                     {
                         for path in paths {
-                            queue.push(FcBuildJob {
+                            self.build_queue.push(FcBuildJob {
                                 priority: Priority::Critical,
                                 path: path.clone(),
                                 font_index: None,
@@ -368,11 +756,8 @@ impl FcFontRegistry {
                     }
                 }
             }
-
-            // Sort so Critical jobs are at the end (popped first with pop())
-            queue.sort();
         }
-        self.queue_condvar.notify_all();
+        self.parker.wake_all();
 
         // 6. Register a pending request and wait for completion
         let satisfied = Arc::new(AtomicBool::new(false));
@@ -404,9 +789,6 @@ impl FcFontRegistry {
             let remaining = deadline.saturating_duration_since(Instant::now());
             let _result = self.request_complete.wait_timeout(pending, remaining);
         }
-
-        // 8. Resolve chains from the now-populated registry
-        self.resolve_chains(&expanded_stacks)
     }
 
     /// Get font metadata by ID.
@@ -414,17 +796,110 @@ impl FcFontRegistry {
         self.metadata.read().unwrap().get(id).cloned()
     }
 
+    /// Vertical/horizontal metrics for a resolved match, read from the matched face's
+    /// `head`, `hhea`, `OS/2`, and `post` tables and cached on its `FcPattern` since the
+    /// initial scan - no second font-parsing pass needed. `None` if `font_match.id` isn't
+    /// (or is no longer) known to the registry.
+    pub fn metrics(&self, font_match: &FontMatch) -> Option<FontMetrics> {
+        Some(self.get_metadata_by_id(&font_match.id)?.metadata.metrics)
+    }
+
+    /// The CSS `@font-face`-style override ratios that make `fallback_id`'s face occupy
+    /// nearly the same box as `requested_id`'s - see `FallbackMetricOverrides`. Intended for
+    /// when `resolve_font_chain` had to substitute a fallback for the font a caller actually
+    /// asked for: applying these overrides (`size_adjust` first, then the `*_override` ratios)
+    /// lets a renderer minimize the layout shift once the real font loads in and replaces the
+    /// fallback. `None` if either id's metrics aren't known to the registry.
+    pub fn compute_metric_overrides(
+        &self,
+        requested_id: FontId,
+        fallback_id: FontId,
+    ) -> Option<FallbackMetricOverrides> {
+        let metadata_map = self.metadata.read().unwrap();
+        let requested = metadata_map.get(&requested_id)?.metadata.metrics;
+        let fallback = metadata_map.get(&fallback_id)?.metadata.metrics;
+        Some(FallbackMetricOverrides::compute(&requested, &fallback))
+    }
+
     /// Get font bytes for a given font ID (either from memory or disk).
-    pub fn get_font_bytes(&self, id: &FontId) -> Option<Vec<u8>> {
+    ///
+    /// Disk fonts are served through a cached `mmap` when the `mmap` feature is
+    /// enabled, so the Builder (which only needs `CMAP`/`name` tables resident)
+    /// doesn't pull hundreds of whole font files into RAM. Checks `mapped_fonts` first
+    /// (mapped eagerly by the Builder while parsing, and shared across every face a
+    /// `.ttc` yields), then falls back to the per-`FontId` `mmap_cache`, then a full
+    /// `fs::read` if mapping fails (e.g. the filesystem doesn't support it).
+    pub fn get_font_bytes(&self, id: &FontId) -> Option<MappedFontBytes> {
         // Check memory fonts first
         if let Some(font) = self.memory_fonts.read().unwrap().get(id) {
-            return Some(font.bytes.clone());
+            return Some(MappedFontBytes::Owned(Arc::new(font.bytes.clone())));
         }
         // Then check disk fonts
-        if let Some(path) = self.disk_fonts.read().unwrap().get(id) {
-            return std::fs::read(&path.path).ok();
+        let path = self.disk_fonts.read().unwrap().get(id)?.clone();
+
+        #[cfg(feature = "mmap")]
+        if let Some(mapped) = self.mapped_fonts.read().unwrap().get(&path.path) {
+            return Some(MappedFontBytes::Mapped(Arc::clone(mapped)));
+        }
+
+        #[cfg(feature = "mmap")]
+        if let Some(mapped) = self.mmap_font(*id, &path.path) {
+            return Some(mapped);
+        }
+
+        std::fs::read(&path.path)
+            .ok()
+            .map(|bytes| MappedFontBytes::Owned(Arc::new(bytes)))
+    }
+
+    /// Memory-map `path` once and cache it in `mapped_fonts`, so every face this file
+    /// yields (and any later `get_font_bytes` call for one of them) shares a single open
+    /// file descriptor and mapping. Called by the Builder right after a successful parse;
+    /// a no-op if `path` is already mapped or can't be mapped.
+    #[cfg(feature = "mmap")]
+    fn mmap_and_cache_path(&self, path: &Path) {
+        let path_str = match path.to_str() {
+            Some(s) => s,
+            None => return,
+        };
+        if self.mapped_fonts.read().unwrap().contains_key(path_str) {
+            return;
+        }
+
+        let file = match std::fs::File::open(path) {
+            Ok(f) => f,
+            Err(_) => return,
+        };
+        // Safety: see `mmap_font` — the mapped file is treated as read-only font data
+        // for the registry's lifetime.
+        if let Ok(mmap) = unsafe { memmap2::Mmap::map(&file) } {
+            self.mapped_fonts
+                .write()
+                .unwrap()
+                .insert(path_str.to_string(), Arc::new(mmap));
         }
-        None
+    }
+
+    /// Map (or reuse a cached mapping of) a disk font's bytes.
+    #[cfg(feature = "mmap")]
+    fn mmap_font(&self, id: FontId, path: &str) -> Option<MappedFontBytes> {
+        if let Some(weak) = self.mmap_cache.lock().unwrap().get(&id) {
+            if let Some(mmap) = weak.upgrade() {
+                return Some(MappedFontBytes::Mapped(mmap));
+            }
+        }
+
+        let file = std::fs::File::open(path).ok()?;
+        // Safety: the mapped file is treated as read-only font data for the lifetime
+        // of the `Mmap`; truncation/modification by another process while mapped is
+        // the usual (accepted) mmap-on-a-mutable-file hazard.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.ok()?;
+        let mmap = Arc::new(mmap);
+        self.mmap_cache
+            .lock()
+            .unwrap()
+            .insert(id, Arc::downgrade(&mmap));
+        Some(MappedFontBytes::Mapped(mmap))
     }
 
     /// Get the disk font path for a font ID.
@@ -432,6 +907,20 @@ impl FcFontRegistry {
         self.disk_fonts.read().unwrap().get(id).cloned()
     }
 
+    /// The face index `id` was parsed from within its font file - `0` for a standalone
+    /// `.ttf`/`.otf`, or the position of the matched face inside a `.ttc`/`.otc` collection.
+    /// Mirrors `FcFontCache::face_index_for_id` for callers driven by the registry instead.
+    pub fn face_index_for_id(&self, id: &FontId) -> Option<usize> {
+        if let Some(font) = self.memory_fonts.read().unwrap().get(id) {
+            return Some(font.font_index);
+        }
+        self.disk_fonts
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|path| path.font_index)
+    }
+
     /// Check if a font ID is a memory font.
     pub fn is_memory_font(&self, id: &FontId) -> bool {
         self.memory_fonts.read().unwrap().contains_key(id)
@@ -483,6 +972,8 @@ impl FcFontRegistry {
             id: *id,
             unicode_ranges: meta.unicode_ranges.clone(),
             fallbacks: Vec::new(),
+            synthesis: FontSynthesis::compute(pattern, meta),
+            instantiated_weight: FcFontCache::instantiated_weight_for(pattern, meta),
         })
     }
 
@@ -500,6 +991,7 @@ impl FcFontRegistry {
             weight,
             italic,
             oblique,
+            languages: Vec::new(),
         };
 
         if let Ok(cache) = self.chain_cache.lock() {
@@ -509,10 +1001,11 @@ impl FcFontRegistry {
         }
 
         // Expand generic families
-        let expanded = crate::expand_font_families(font_families, self.os, &[]);
+        let expanded =
+            crate::expand_font_families(font_families, self.os, &self.generic_alias_overrides());
 
         // Build chain
-        let chain = self.resolve_font_chain_uncached(&expanded, weight, italic, oblique);
+        let chain = self.resolve_font_chain_uncached(&expanded, weight, italic, oblique, &[]);
 
         // Cache it
         if let Ok(mut cache) = self.chain_cache.lock() {
@@ -522,6 +1015,563 @@ impl FcFontRegistry {
         chain
     }
 
+    /// Like `resolve_font_chain`, but additionally scores candidates by how well they cover
+    /// `languages` (BCP-47 tags, e.g. `zh-CN`, `ja`, `ko`) before falling back to style and
+    /// coverage — the same per-face language data `FcFontCache` already extracts from the
+    /// OS/2 codepage range during scanning (see `extract_languages_from_codepage_range`),
+    /// now consulted here too. Without this, a Han-unified "CJK" family can render the wrong
+    /// glyph forms for a language it wasn't scored against; mirrors how Ruffle picks a
+    /// different bundled CJK font per requested language on Windows.
+    pub fn resolve_font_chain_for_languages(
+        &self,
+        font_families: &[String],
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+        languages: &[String],
+    ) -> FontFallbackChain {
+        let cache_key = FontChainCacheKey {
+            font_families: font_families.to_vec(),
+            weight,
+            italic,
+            oblique,
+            languages: languages.to_vec(),
+        };
+
+        if let Ok(cache) = self.chain_cache.lock() {
+            if let Some(cached) = cache.get(&cache_key) {
+                return cached.clone();
+            }
+        }
+
+        let expanded =
+            crate::expand_font_families(font_families, self.os, &self.generic_alias_overrides());
+
+        let chain = self.resolve_font_chain_uncached(&expanded, weight, italic, oblique, languages);
+
+        if let Ok(mut cache) = self.chain_cache.lock() {
+            cache.insert(cache_key, chain.clone());
+        }
+
+        chain
+    }
+
+    /// Like `resolve_font_chain`, but drops any candidate whose weight/stretch/italic/oblique
+    /// isn't an exact match for the request instead of silently accepting the closest-scoring
+    /// near-miss `resolve_font_chain` would - equivalent to `FcPattern::exact_style`/
+    /// `MatchStrictness::ExactStyle` at the single-font `query`/`query_exact` level, applied
+    /// across a whole fallback chain. A tool that must verify a specific face exists (e.g.
+    /// embedding glyphs for exactly "SemiBold") needs to know a family only ships
+    /// Regular/Bold, not silently get Bold back. Groups (and `unicode_fallbacks`) left with no
+    /// exact match are dropped entirely rather than returned with a near-miss; `trace` records
+    /// why each dropped face was rejected, via the same `MatchReason::ExactStyleMismatch`
+    /// `FcFontCache::exact_style_matches` already pushes for `query`/`query_all`.
+    pub fn resolve_font_chain_exact(
+        &self,
+        font_families: &[String],
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+        trace: &mut Vec<TraceMsg>,
+    ) -> FontFallbackChain {
+        let requested = FcPattern {
+            weight,
+            italic,
+            oblique,
+            ..Default::default()
+        };
+
+        let chain = self.resolve_font_chain(font_families, weight, italic, oblique);
+        let metadata_map = self.metadata.read().unwrap();
+
+        let mut is_exact = |id: FontId| -> bool {
+            metadata_map
+                .get(&id)
+                .map(|meta| FcFontCache::exact_style_matches(&requested, meta, trace))
+                .unwrap_or(false)
+        };
+
+        let css_fallbacks = chain
+            .css_fallbacks
+            .into_iter()
+            .filter_map(|mut group| {
+                group.fonts.retain(|font_match| is_exact(font_match.id));
+                if group.fonts.is_empty() {
+                    None
+                } else {
+                    Some(group)
+                }
+            })
+            .collect();
+
+        let unicode_fallbacks = chain
+            .unicode_fallbacks
+            .into_iter()
+            .filter(|font_match| is_exact(font_match.id))
+            .collect();
+
+        FontFallbackChain {
+            css_fallbacks,
+            unicode_fallbacks,
+            original_stack: chain.original_stack,
+        }
+    }
+
+    /// Picks `specific` for a style slot, falling back to `fallback` when the slot is either
+    /// unset (`None`) or explicitly set to an empty stack - an empty `Vec` means "nothing
+    /// configured for this slot" just as much as `None` does, so callers that build
+    /// `StyleFamilies` from e.g. parsed CSS (where an empty `font-family` list is easy to end
+    /// up with) don't need to normalize it to `None` themselves.
+    fn style_stack<'a>(specific: Option<&'a Vec<String>>, fallback: &'a Vec<String>) -> &'a Vec<String> {
+        match specific {
+            Some(stack) if !stack.is_empty() => stack,
+            _ => fallback,
+        }
+    }
+
+    /// Resolve a normal/bold/italic/bold-italic quartet of chains, allowing each style to
+    /// request its own `font-family` stack (e.g. a terminal pinning "monospace" for roman
+    /// text but a distinct family for bold). Any style slot left as `None` - or given an
+    /// empty stack, see `style_stack` - falls back to the `regular` family with synthetic
+    /// weight/slant selection, as `resolve_font_chain` does.
+    pub fn resolve_font_chain_per_style(&self, families: &StyleFamilies) -> PerStyleFontChain {
+        let regular = self.resolve_font_chain(
+            &families.regular,
+            FcWeight::Normal,
+            PatternMatch::False,
+            PatternMatch::False,
+        );
+
+        let bold_stack = Self::style_stack(families.bold.as_ref(), &families.regular);
+        let bold = self.resolve_font_chain(
+            bold_stack,
+            FcWeight::Bold,
+            PatternMatch::False,
+            PatternMatch::False,
+        );
+
+        let italic_stack = Self::style_stack(families.italic.as_ref(), &families.regular);
+        let italic = self.resolve_font_chain(
+            italic_stack,
+            FcWeight::Normal,
+            PatternMatch::True,
+            PatternMatch::DontCare,
+        );
+
+        let bold_italic_fallback = Self::style_stack(
+            families.bold.as_ref(),
+            Self::style_stack(families.italic.as_ref(), &families.regular),
+        );
+        let bold_italic_stack =
+            Self::style_stack(families.bold_italic.as_ref(), bold_italic_fallback);
+        let bold_italic = self.resolve_font_chain(
+            bold_italic_stack,
+            FcWeight::Bold,
+            PatternMatch::True,
+            PatternMatch::DontCare,
+        );
+
+        PerStyleFontChain {
+            regular,
+            bold,
+            italic,
+            bold_italic,
+        }
+    }
+
+    /// Like `resolve_font_chain_per_style`, but also fills in `unicode_fallbacks` for `text`
+    /// on each of the four resolved chains, the way `resolve_font_chain_for_text` does for a
+    /// single stack. A single `char_decisions` cache is shared across all four slots, so a
+    /// codepoint `css_fallbacks` leaves uncovered in more than one slot (typical, since bold
+    /// and italic usually only override a couple of style properties, not the whole coverage
+    /// picture) is only resolved via `fonts_covering` once rather than up to four times.
+    pub fn resolve_font_chain_per_style_for_text(
+        &self,
+        families: &StyleFamilies,
+        text: &str,
+    ) -> PerStyleFontChain {
+        let mut chains = self.resolve_font_chain_per_style(families);
+        let mut char_decisions = BTreeMap::new();
+
+        chains.regular.unicode_fallbacks = self.unicode_fallback_tail(
+            &chains.regular,
+            FcWeight::Normal,
+            PatternMatch::False,
+            PatternMatch::False,
+            text,
+            &mut char_decisions,
+        );
+        chains.bold.unicode_fallbacks = self.unicode_fallback_tail(
+            &chains.bold,
+            FcWeight::Bold,
+            PatternMatch::False,
+            PatternMatch::False,
+            text,
+            &mut char_decisions,
+        );
+        chains.italic.unicode_fallbacks = self.unicode_fallback_tail(
+            &chains.italic,
+            FcWeight::Normal,
+            PatternMatch::True,
+            PatternMatch::DontCare,
+            text,
+            &mut char_decisions,
+        );
+        chains.bold_italic.unicode_fallbacks = self.unicode_fallback_tail(
+            &chains.bold_italic,
+            FcWeight::Bold,
+            PatternMatch::True,
+            PatternMatch::DontCare,
+            text,
+            &mut char_decisions,
+        );
+
+        chains
+    }
+
+    /// Resolve a fallback chain like `resolve_font_chain`, but also populate
+    /// `unicode_fallbacks`: fonts not requested by any family name in `font_families`
+    /// that nonetheless cover codepoints of `text` the named families left uncovered
+    /// (CJK, emoji, Arabic, ...). Looks these up via `coverage_index` instead of
+    /// relying on family-name guessing, mirroring `FcFontCache::query_for_text`.
+    pub fn resolve_font_chain_for_text(
+        &self,
+        font_families: &[String],
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+        text: &str,
+    ) -> FontFallbackChain {
+        let mut chain = self.resolve_font_chain(font_families, weight, italic, oblique);
+        let mut char_decisions = BTreeMap::new();
+        chain.unicode_fallbacks =
+            self.unicode_fallback_tail(&chain, weight, italic, oblique, text, &mut char_decisions);
+        chain
+    }
+
+    /// Faces chosen purely by Unicode coverage to fill in whatever `chain.css_fallbacks`
+    /// leaves uncovered in `text`, requested with style `(weight, italic, oblique)`. Shared
+    /// by `resolve_font_chain_for_text` and `resolve_font_chain_per_style_for_text`; the
+    /// latter passes one `char_decisions` map across all four style slots so a codepoint
+    /// common to several slots (most text, most of the time) only walks `fonts_covering`
+    /// once instead of once per slot.
+    fn unicode_fallback_tail(
+        &self,
+        chain: &FontFallbackChain,
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+        text: &str,
+        char_decisions: &mut BTreeMap<char, FontId>,
+    ) -> Vec<FontMatch> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let requested = FcPattern {
+            weight,
+            italic,
+            oblique,
+            ..Default::default()
+        };
+
+        let metadata = self.metadata.read().unwrap();
+        let chars: Vec<char> = text.chars().collect();
+        let mut covered_chars = vec![false; chars.len()];
+        let mut seen: BTreeSet<FontId> = BTreeSet::new();
+
+        for group in &chain.css_fallbacks {
+            for font_match in &group.fonts {
+                seen.insert(font_match.id);
+                if let Some(meta) = metadata.get(&font_match.id) {
+                    for (i, &c) in chars.iter().enumerate() {
+                        if !covered_chars[i] && meta.contains_char(c) {
+                            covered_chars[i] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if covered_chars.iter().all(|&c| c) {
+            return Vec::new();
+        }
+
+        let mut unicode_fallbacks = Vec::new();
+
+        for (i, &c) in chars.iter().enumerate() {
+            if covered_chars[i] {
+                continue;
+            }
+
+            let chosen = if let Some(&id) = char_decisions.get(&c) {
+                Some(id)
+            } else {
+                // Among every face covering this codepoint, prefer the one closest to the
+                // requested style/weight instead of an arbitrary one - `fonts_covering` makes
+                // no style guarantee, so without this a CJK fallback could come back in the
+                // wrong weight even though a better-matching face also covers the codepoint.
+                // Tie-broken by `FontId` so the chain stays deterministic.
+                let decision = self
+                    .fonts_covering(c as u32)
+                    .into_iter()
+                    .map(|id| {
+                        let score = metadata
+                            .get(&id)
+                            .map(|meta| FcFontCache::calculate_style_score(&requested, meta))
+                            .unwrap_or(i32::MAX);
+                        (score, id)
+                    })
+                    .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)))
+                    .map(|(_, id)| id);
+                if let Some(id) = decision {
+                    char_decisions.insert(c, id);
+                }
+                decision
+            };
+
+            let id = match chosen {
+                Some(id) => id,
+                None => continue,
+            };
+            if !seen.insert(id) {
+                continue;
+            }
+            if let Some(meta) = metadata.get(&id) {
+                unicode_fallbacks.push(FontMatch {
+                    id,
+                    unicode_ranges: meta.unicode_ranges.clone(),
+                    fallbacks: Vec::new(),
+                    synthesis: FontSynthesis::compute(&requested, meta),
+                    instantiated_weight: FcFontCache::instantiated_weight_for(&requested, meta),
+                });
+            }
+        }
+
+        unicode_fallbacks
+    }
+
+    /// Identify where a loaded font's bytes ultimately came from, for `explain_chain`.
+    fn font_origin(&self, id: FontId) -> FontOrigin {
+        if self.memory_fonts.read().unwrap().contains_key(&id) {
+            return FontOrigin::Memory;
+        }
+
+        let path = self.disk_fonts.read().unwrap().get(&id).cloned();
+        match path {
+            #[cfg(feature = "cache")]
+            Some(path) if self.cache_loaded_ids.lock().unwrap().contains(&id) => {
+                FontOrigin::DiskCache(path)
+            }
+            Some(path) => FontOrigin::Disk(path),
+            None => FontOrigin::Memory,
+        }
+    }
+
+    /// Like `resolve_font_chain`, but instead of discarding the scoring and trace data
+    /// computed along the way, returns it: a `ChainExplanation` with, for each requested
+    /// family, every candidate face considered (in the order it would be picked), its
+    /// resolved `FontOrigin`, the token-similarity/style scores `fuzzy_query_by_name_internal`
+    /// computed, and the `query_matches_internal` trace for its style match.
+    ///
+    /// Meant for debugging an unexpected resolution (`Display`s like wezterm's `ls-fonts`),
+    /// not for the hot layout path — it re-does the work `resolve_font_chain` already cached.
+    pub fn explain_chain(
+        &self,
+        font_families: &[String],
+        weight: FcWeight,
+        italic: PatternMatch,
+        oblique: PatternMatch,
+    ) -> ChainExplanation {
+        let expanded =
+            crate::expand_font_families(font_families, self.os, &self.generic_alias_overrides());
+        let chain = self.resolve_font_chain_uncached(&expanded, weight, italic, oblique, &[]);
+
+        let metadata_map = self.metadata.read().unwrap();
+        let style_pattern = FcPattern {
+            weight,
+            italic,
+            oblique,
+            ..Default::default()
+        };
+
+        let families = chain
+            .css_fallbacks
+            .into_iter()
+            .map(|group| {
+                let candidates = group
+                    .fonts
+                    .into_iter()
+                    .map(|font_match| {
+                        let pattern = metadata_map
+                            .get(&font_match.id)
+                            .cloned()
+                            .unwrap_or_default();
+
+                        let requested_tokens =
+                            FcFontCache::extract_font_name_tokens(&group.css_name);
+                        let is_generic = matches!(
+                            group.css_name.to_lowercase().as_str(),
+                            "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
+                        );
+                        let token_similarity = if is_generic || requested_tokens.is_empty() {
+                            None
+                        } else {
+                            let font_tokens = self.font_tokens.read().unwrap();
+                            font_tokens.get(&font_match.id).map(|ft| {
+                                let matched = requested_tokens
+                                    .iter()
+                                    .filter(|req_token| {
+                                        let req_lower = req_token.to_lowercase();
+                                        ft.iter().any(|font_token| font_token.contains(&req_lower))
+                                    })
+                                    .count();
+                                (matched * 100 / requested_tokens.len()) as i32
+                            })
+                        };
+
+                        let style_score = FcFontCache::calculate_style_score(&style_pattern, &pattern);
+
+                        let mut trace = Vec::new();
+                        FcFontCache::query_matches_internal(&pattern, &style_pattern, &mut trace);
+
+                        ExplainedCandidate {
+                            origin: self.font_origin(font_match.id),
+                            pattern,
+                            token_similarity,
+                            style_score,
+                            trace,
+                        }
+                    })
+                    .collect();
+
+                ExplainedFamily {
+                    css_name: group.css_name,
+                    candidates,
+                }
+            })
+            .collect();
+
+        ChainExplanation { families }
+    }
+
+    /// Segment `text` into maximal sub-runs each coverable by a single font, for callers
+    /// driving a shaper in one call instead of per-glyph round-trips.
+    ///
+    /// Walks `text` codepoint by codepoint, querying `coverage_index` intersected with
+    /// the style constraints of `pattern` (name/family/weight/slant/monospace, via
+    /// `FcFontCache::query_matches_internal`) and greedily extends the current run while
+    /// its chosen `FontId` still covers the next codepoint. When coverage breaks, a new
+    /// run starts, picking the best-covering font via `calculate_unicode_compatibility`/
+    /// `calculate_style_score`. Combining marks and variation selectors always stay
+    /// attached to the preceding run's font, even when that font's own coverage of them
+    /// is ambiguous, since splitting a base character from its combining marks would
+    /// corrupt shaping.
+    pub fn match_cluster(&self, text: &str, pattern: &FcPattern) -> Vec<(Range<usize>, FontId)> {
+        let metadata = self.metadata.read().unwrap();
+        let coverage_index = self.coverage_index.read().unwrap();
+
+        let mut runs: Vec<(Range<usize>, FontId)> = Vec::new();
+        let mut current_font: Option<FontId> = None;
+        let mut run_start = 0usize;
+
+        for (byte_offset, c) in text.char_indices() {
+            let codepoint = c as u32;
+
+            if is_combining_or_variation_selector(codepoint) && current_font.is_some() {
+                continue;
+            }
+
+            // Keep extending the open run while its font still covers this codepoint.
+            if let Some(meta) = current_font.and_then(|font| metadata.get(&font)) {
+                let ranges = if !meta.cmap_coverage.is_empty() {
+                    &meta.cmap_coverage
+                } else {
+                    &meta.unicode_ranges
+                };
+                if ranges.iter().any(|r| r.start <= codepoint && r.end >= codepoint) {
+                    continue;
+                }
+            }
+
+            match Self::best_covering_font_internal(codepoint, pattern, &metadata, &coverage_index) {
+                Some(candidate) if current_font != Some(candidate) => {
+                    if let Some(font) = current_font {
+                        runs.push((run_start..byte_offset, font));
+                    }
+                    current_font = Some(candidate);
+                    run_start = byte_offset;
+                }
+                Some(_) => {
+                    // Same font as the current run; just extend it.
+                }
+                None if current_font.is_none() => {
+                    // Nothing covers this codepoint and no run is open yet; skip it.
+                }
+                None => {
+                    // Nothing covers it, but a run is already open (e.g. an ambiguous
+                    // combining mark) — leave it attached to the current run's font.
+                }
+            }
+        }
+
+        if let Some(font) = current_font {
+            runs.push((run_start..text.len(), font));
+        }
+
+        runs
+    }
+
+    /// Pick the best font covering `codepoint` that also satisfies `pattern`'s style
+    /// constraints, ranking candidates the same way `query`/`resolve_font_chain` do.
+    fn best_covering_font_internal(
+        codepoint: u32,
+        pattern: &FcPattern,
+        metadata: &BTreeMap<FontId, FcPattern>,
+        coverage_index: &BTreeMap<u32, BTreeSet<FontId>>,
+    ) -> Option<FontId> {
+        let mut trace = Vec::new();
+        let mut best: Option<(FontId, i32, i32)> = None;
+
+        for (_, ids) in coverage_index.range(..=codepoint) {
+            for id in ids {
+                let meta = match metadata.get(id) {
+                    Some(meta) => meta,
+                    None => continue,
+                };
+                let ranges = if !meta.cmap_coverage.is_empty() {
+                    &meta.cmap_coverage
+                } else {
+                    &meta.unicode_ranges
+                };
+                if !ranges.iter().any(|r| r.start <= codepoint && r.end >= codepoint) {
+                    continue;
+                }
+                if !FcFontCache::query_matches_internal(meta, pattern, &mut trace) {
+                    continue;
+                }
+
+                let compat = FcFontCache::calculate_unicode_compatibility(
+                    &pattern.unicode_ranges,
+                    &meta.unicode_ranges,
+                );
+                let style_score = FcFontCache::calculate_style_score(pattern, meta);
+
+                let is_better = match best {
+                    None => true,
+                    Some((_, best_compat, best_style)) => {
+                        compat > best_compat || (compat == best_compat && style_score < best_style)
+                    }
+                };
+                if is_better {
+                    best = Some((*id, compat, style_score));
+                }
+            }
+        }
+
+        best.map(|(id, _, _)| id)
+    }
+
     /// Convert the registry into an immutable `FcFontCache` snapshot.
     pub fn into_fc_font_cache(&self) -> FcFontCache {
         let mut cache = FcFontCache::default();
@@ -563,7 +1613,7 @@ impl FcFontRegistry {
     /// Signal all background threads to shut down.
     pub fn shutdown(&self) {
         self.shutdown.store(true, Ordering::Release);
-        self.queue_condvar.notify_all();
+        self.parker.wake_all();
     }
 
     /// Returns true if the Scout has finished enumerating all font directories.
@@ -596,9 +1646,19 @@ impl FcFontRegistry {
         let mut metadata = self.metadata.write().unwrap();
         let mut token_index = self.token_index.write().unwrap();
         let mut font_tokens = self.font_tokens.write().unwrap();
+        let mut coverage_index = self.coverage_index.write().unwrap();
 
         Self::index_pattern_tokens_static(&mut token_index, &mut font_tokens, &pattern, id);
+        Self::index_coverage_static(&mut coverage_index, &pattern, id);
         patterns.insert(pattern.clone(), id);
+        #[cfg(feature = "watch")]
+        {
+            let (mtime_secs, file_size) = get_file_metadata(&path.path);
+            self.file_metadata
+                .write()
+                .unwrap()
+                .insert(path.path.clone(), (mtime_secs, file_size));
+        }
         disk_fonts.insert(id, path);
         metadata.insert(id, pattern);
 
@@ -637,6 +1697,62 @@ impl FcFontRegistry {
         font_tokens.insert(id, tokens_lower);
     }
 
+    /// Static helper for codepoint-coverage indexing (doesn't need &self, works with
+    /// mutable refs). Indexes a font's `cmap_coverage` (or, when that is empty, its declared
+    /// `unicode_ranges`) by each range's start codepoint.
+    fn index_coverage_static(
+        coverage_index: &mut BTreeMap<u32, BTreeSet<FontId>>,
+        pattern: &FcPattern,
+        id: FontId,
+    ) {
+        // Index the real per-glyph `cmap` coverage when it was parsed; it's a finer-grained,
+        // more accurate run-list than the coarse OS/2 block hints in `unicode_ranges`.
+        let ranges = if !pattern.cmap_coverage.is_empty() {
+            &pattern.cmap_coverage
+        } else {
+            &pattern.unicode_ranges
+        };
+        for range in ranges {
+            coverage_index
+                .entry(range.start)
+                .or_insert_with(BTreeSet::new)
+                .insert(id);
+        }
+    }
+
+    /// Look up every font that declares Unicode coverage for `codepoint`.
+    ///
+    /// Candidates are gathered from `coverage_index` (every range whose start is `<=
+    /// codepoint`) and then verified against `metadata` to confirm the range's `end >=
+    /// codepoint`, since a font can have multiple disjoint ranges and only the starts are
+    /// indexed. Used by `resolve_font_chain_for_text` to extend a fallback chain with
+    /// whatever font actually covers a requested script (CJK, emoji, Arabic, ...) instead
+    /// of relying on family-name guessing.
+    pub fn fonts_covering(&self, codepoint: u32) -> Vec<FontId> {
+        let coverage_index = self.coverage_index.read().unwrap();
+        let metadata = self.metadata.read().unwrap();
+
+        let mut found = BTreeSet::new();
+        for (_, ids) in coverage_index.range(..=codepoint) {
+            for id in ids {
+                if found.contains(id) {
+                    continue;
+                }
+                if let Some(pattern) = metadata.get(id) {
+                    let ranges = if !pattern.cmap_coverage.is_empty() {
+                        &pattern.cmap_coverage
+                    } else {
+                        &pattern.unicode_ranges
+                    };
+                    if ranges.iter().any(|r| r.start <= codepoint && r.end >= codepoint) {
+                        found.insert(*id);
+                    }
+                }
+            }
+        }
+        found.into_iter().collect()
+    }
+
     /// Check and signal any pending requests that are now satisfied.
     fn check_and_signal_pending_requests(&self) {
         let mut pending = self.pending_requests.lock().unwrap();
@@ -688,30 +1804,72 @@ impl FcFontRegistry {
             .collect()
     }
 
-    /// Internal chain resolution without caching.
+    /// Internal chain resolution without caching. `languages` (BCP-47 tags) is consulted
+    /// when scoring candidates so a Han-unified "CJK" family resolves to the face whose
+    /// glyph forms are correct for the requested locale; pass `&[]` to match on
+    /// name/style/coverage alone, as `resolve_font_chain` does.
     fn resolve_font_chain_uncached(
         &self,
         font_families: &[String],
         weight: FcWeight,
         italic: PatternMatch,
         oblique: PatternMatch,
+        languages: &[String],
     ) -> FontFallbackChain {
         let patterns = self.patterns.read().unwrap();
         let metadata_map = self.metadata.read().unwrap();
         let token_index = self.token_index.read().unwrap();
         let font_tokens_map = self.font_tokens.read().unwrap();
         let memory_fonts = self.memory_fonts.read().unwrap();
+        let generic_aliases = self.generic_aliases.read().unwrap();
 
         let mut css_fallbacks = Vec::new();
         let mut trace = Vec::new();
 
         for family in font_families {
+            let lower_family = family.to_lowercase();
             let is_generic = matches!(
-                family.to_lowercase().as_str(),
+                lower_family.as_str(),
                 "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui"
             );
 
             let matches = if is_generic {
+                // If the system's fontconfig aliased this generic to a concrete,
+                // user-preferred family (e.g. "serif" -> "DejaVu Serif"), try resolving
+                // that by name first; fall back to the monospace-boolean-only match
+                // below for whatever it doesn't cover.
+                let mut found: Vec<FontMatch> = Vec::new();
+                // "monospace" is the one generic whose concrete substitutes must actually be
+                // fixed-pitch: a proportional face that merely *sounds* monospaced (name
+                // token matching alone can't tell) would otherwise leak into terminal-style
+                // runs. Every other generic doesn't care about spacing.
+                let required_spacing = if lower_family == "monospace" {
+                    FcSpacing::Mono
+                } else {
+                    FcSpacing::DontCare
+                };
+
+                if let Some(preferred) = generic_aliases.get(&lower_family) {
+                    for concrete_family in preferred {
+                        found.extend(self.fuzzy_query_by_name_internal(
+                            concrete_family,
+                            weight,
+                            italic,
+                            oblique,
+                            languages,
+                            required_spacing,
+                            &patterns,
+                            &metadata_map,
+                            &token_index,
+                            &font_tokens_map,
+                            &memory_fonts,
+                        ));
+                        if found.len() >= 5 {
+                            break;
+                        }
+                    }
+                }
+
                 // Generic families need full pattern matching
                 let pattern = match family.as_str() {
                     "monospace" => FcPattern {
@@ -720,7 +1878,9 @@ impl FcFontRegistry {
                         italic,
                         oblique,
                         monospace: PatternMatch::True,
+                        spacing: FcSpacing::Mono,
                         unicode_ranges: Vec::new(),
+                        languages: languages.to_vec(),
                         ..Default::default()
                     },
                     _ => FcPattern {
@@ -730,38 +1890,117 @@ impl FcFontRegistry {
                         oblique,
                         monospace: PatternMatch::False,
                         unicode_ranges: Vec::new(),
+                        languages: languages.to_vec(),
                         ..Default::default()
                     },
                 };
 
-                let mut found = Vec::new();
+                let already_found: HashSet<FontId> = found.iter().map(|m| m.id).collect();
+                let mut scored: Vec<(FontId, i32, i32, i32, &FcPattern)> = Vec::new();
                 for (stored_pattern, id) in patterns.iter() {
-                    if FcFontCache::query_matches_internal(stored_pattern, &pattern, &mut trace) {
-                        let meta = metadata_map.get(id).unwrap_or(stored_pattern);
-                        found.push(FontMatch {
-                            id: *id,
-                            unicode_ranges: meta.unicode_ranges.clone(),
-                            fallbacks: Vec::new(),
-                        });
+                    if already_found.contains(id) {
+                        continue;
+                    }
+                    // `monospace`/`spacing` stay hard requirements - a proportional face is
+                    // never an acceptable substitute for a monospace request. Weight and
+                    // slant (italic/oblique) no longer reject outright on mismatch: they're
+                    // scored via `FcPattern::find_best_match`'s combined distance instead, so
+                    // a generic family with no exact style match still returns its closest
+                    // face rather than being dropped from the chain entirely.
+                    if pattern.monospace.needs_to_match()
+                        && !pattern.monospace.matches(&stored_pattern.monospace)
+                    {
+                        continue;
+                    }
+                    if !pattern.spacing.satisfies(stored_pattern.spacing) {
+                        continue;
                     }
+
+                    let meta = metadata_map.get(id).unwrap_or(stored_pattern);
+                    let style_distance = pattern
+                        .find_best_match(std::slice::from_ref(&(*id, meta.clone())))
+                        .map(|(_, distance)| distance)
+                        .unwrap_or(0);
+                    trace.push(TraceMsg {
+                        level: TraceLevel::Debug,
+                        path: meta
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| "<unknown>".to_string()),
+                        reason: MatchReason::StyleScored {
+                            distance: style_distance,
+                        },
+                    });
+                    let language_score =
+                        FcFontCache::calculate_language_score(&pattern, meta) as i32;
+                    let style_score = FcFontCache::calculate_style_score(&pattern, meta);
+                    scored.push((*id, language_score, style_distance, style_score, meta));
                 }
+                // Highest language-tag overlap first, then closest weight/slant distance,
+                // then best (lowest) overall style score.
+                scored.sort_by(|a, b| {
+                    b.1.cmp(&a.1)
+                        .then_with(|| a.2.cmp(&b.2))
+                        .then_with(|| a.3.cmp(&b.3))
+                });
+                found.extend(scored.into_iter().map(|(id, _, _, _, meta)| FontMatch {
+                    id,
+                    unicode_ranges: meta.unicode_ranges.clone(),
+                    fallbacks: Vec::new(),
+                    synthesis: FontSynthesis::compute(&pattern, meta),
+                    instantiated_weight: FcFontCache::instantiated_weight_for(&pattern, meta),
+                }));
                 if found.len() > 5 {
                     found.truncate(5);
                 }
-                found
-            } else {
-                // Specific font: use token-based fuzzy matching
-                self.fuzzy_query_by_name_internal(
-                    family,
-                    weight,
-                    italic,
-                    oblique,
-                    &patterns,
-                    &metadata_map,
-                    &token_index,
-                    &font_tokens_map,
-                    &memory_fonts,
-                )
+                found
+            } else {
+                // Specific font: first try any `<alias>` substitution fontconfig declared for
+                // this exact family name (e.g. "Helvetica" -> "Arial"), then fall through to
+                // matching the family itself so the alias augments rather than replaces the
+                // base lookup.
+                let mut matches = Vec::new();
+                if let Some(preferred) = generic_aliases.get(&lower_family) {
+                    for concrete_family in preferred {
+                        if concrete_family.eq_ignore_ascii_case(family) {
+                            continue;
+                        }
+                        matches.extend(self.fuzzy_query_by_name_internal(
+                            concrete_family,
+                            weight,
+                            italic,
+                            oblique,
+                            languages,
+                            FcSpacing::DontCare,
+                            &patterns,
+                            &metadata_map,
+                            &token_index,
+                            &font_tokens_map,
+                            &memory_fonts,
+                        ));
+                        if !matches.is_empty() {
+                            break;
+                        }
+                    }
+                }
+
+                if matches.is_empty() {
+                    matches = self.fuzzy_query_by_name_internal(
+                        family,
+                        weight,
+                        italic,
+                        oblique,
+                        languages,
+                        FcSpacing::DontCare,
+                        &patterns,
+                        &metadata_map,
+                        &token_index,
+                        &font_tokens_map,
+                        &memory_fonts,
+                    );
+                }
+
+                matches
             };
 
             css_fallbacks.push(CssFallbackGroup {
@@ -770,6 +2009,19 @@ impl FcFontRegistry {
             });
         }
 
+        // Every requested family failed to match anything: rather than leave the caller
+        // with nothing to render, grab any font the registry actually loaded.
+        if css_fallbacks.iter().all(|group| group.fonts.is_empty()) {
+            if let Some(last_resort) =
+                Self::last_resort_font_match(self.os, &patterns, &metadata_map, &mut trace)
+            {
+                css_fallbacks.push(CssFallbackGroup {
+                    css_name: "<last-resort>".to_string(),
+                    fonts: vec![last_resort],
+                });
+            }
+        }
+
         FontFallbackChain {
             css_fallbacks,
             unicode_fallbacks: Vec::new(),
@@ -777,13 +2029,73 @@ impl FcFontRegistry {
         }
     }
 
+    /// Last-ditch substitution for a totally empty fallback chain: try the OS's common
+    /// families in priority order (mirroring the Scout thread's own prioritization), then
+    /// just grab the first font the registry has loaded at all. Mirrors Servo's
+    /// `last_resort_font_families` and Zed's "try known names, then grab whatever the
+    /// system reports" fallback — guaranteeing text renders with *something* instead of
+    /// nothing.
+    fn last_resort_font_match(
+        os: OperatingSystem,
+        patterns: &BTreeMap<FcPattern, FontId>,
+        metadata_map: &BTreeMap<FontId, FcPattern>,
+        trace: &mut Vec<TraceMsg>,
+    ) -> Option<FontMatch> {
+        let common_families = get_common_font_families_for_os(os);
+
+        let mut chosen: Option<(FontId, &FcPattern)> = None;
+        'outer: for common in &common_families {
+            for (stored_pattern, id) in patterns.iter() {
+                let matches_common = stored_pattern
+                    .name
+                    .as_ref()
+                    .map(|n| normalize_family_name(n).contains(common.as_str()))
+                    .unwrap_or(false)
+                    || stored_pattern
+                        .family
+                        .as_ref()
+                        .map(|f| normalize_family_name(f).contains(common.as_str()))
+                        .unwrap_or(false);
+                if matches_common {
+                    chosen = Some((*id, stored_pattern));
+                    break 'outer;
+                }
+            }
+        }
+
+        let (id, fallback_pattern) =
+            chosen.or_else(|| patterns.iter().next().map(|(p, id)| (*id, p)))?;
+        let meta = metadata_map.get(&id).unwrap_or(fallback_pattern);
+
+        trace.push(TraceMsg {
+            level: TraceLevel::Warning,
+            path: meta
+                .name
+                .clone()
+                .unwrap_or_else(|| "<unknown>".to_string()),
+            reason: MatchReason::Success,
+        });
+
+        Some(FontMatch {
+            id,
+            unicode_ranges: meta.unicode_ranges.clone(),
+            fallbacks: Vec::new(),
+            synthesis: FontSynthesis::compute(&FcPattern::default(), meta),
+            instantiated_weight: FcFontCache::instantiated_weight_for(&FcPattern::default(), meta),
+        })
+    }
+
     /// Token-based fuzzy matching (same algorithm as FcFontCache but using read locks).
+    /// `languages` (BCP-47 tags) breaks ties between equally name-similar candidates in
+    /// favor of the one whose advertised language coverage matches the request.
     fn fuzzy_query_by_name_internal(
         &self,
         requested_name: &str,
         weight: FcWeight,
         italic: PatternMatch,
         oblique: PatternMatch,
+        languages: &[String],
+        required_spacing: FcSpacing,
         _patterns: &BTreeMap<FcPattern, FontId>,
         metadata_map: &BTreeMap<FontId, FcPattern>,
         token_index: &BTreeMap<String, BTreeSet<FontId>>,
@@ -817,6 +2129,20 @@ impl FcFontRegistry {
             }
         }
 
+        let requested = FcPattern {
+            weight,
+            italic,
+            oblique,
+            languages: languages.to_vec(),
+            spacing: required_spacing,
+            ..Default::default()
+        };
+
+        // Name-token matching alone can't tell a genuinely fixed-pitch face from one that
+        // merely has "Mono" in its name, so a spacing request is enforced as a hard
+        // preference here rather than folded into `style_score`'s usual penalty scale.
+        const SPACING_MISMATCH_PENALTY: i32 = 1_000;
+
         let mut candidates = Vec::new();
         for id in candidate_ids {
             let pattern = match metadata_map.get(&id) {
@@ -840,28 +2166,32 @@ impl FcFontRegistry {
             }
 
             let token_similarity = (token_matches * 100 / tokens.len()) as i32;
-            let style_score = FcFontCache::calculate_style_score(
-                &FcPattern {
-                    weight,
-                    italic,
-                    oblique,
-                    ..Default::default()
-                },
-                pattern,
-            );
+            let language_score = FcFontCache::calculate_language_score(&requested, pattern) as i32;
+            let spacing_ok = required_spacing.satisfies(pattern.spacing);
+            let mut style_score = FcFontCache::calculate_style_score(&requested, pattern);
+            if !spacing_ok {
+                style_score += SPACING_MISMATCH_PENALTY;
+            }
 
-            candidates.push((id, token_similarity, style_score, pattern.clone()));
+            candidates.push((id, spacing_ok, token_similarity, language_score, style_score, pattern.clone()));
         }
 
-        candidates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.2.cmp(&b.2)));
+        candidates.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| b.2.cmp(&a.2))
+                .then_with(|| b.3.cmp(&a.3))
+                .then_with(|| a.4.cmp(&b.4))
+        });
         candidates.truncate(5);
 
         candidates
             .into_iter()
-            .map(|(id, _, _, pattern)| FontMatch {
+            .map(|(id, _, _, _, _, pattern)| FontMatch {
                 id,
                 unicode_ranges: pattern.unicode_ranges.clone(),
                 fallbacks: Vec::new(),
+                synthesis: FontSynthesis::compute(&requested, &pattern),
+                instantiated_weight: FcFontCache::instantiated_weight_for(&requested, &pattern),
             })
             .collect()
     }
@@ -870,7 +2200,7 @@ impl FcFontRegistry {
 impl Drop for FcFontRegistry {
     fn drop(&mut self) {
         self.shutdown.store(true, Ordering::Release);
-        self.queue_condvar.notify_all();
+        self.parker.wake_all();
     }
 }
 
@@ -878,7 +2208,16 @@ impl Drop for FcFontRegistry {
 
 /// The Scout thread: enumerates font directories and populates the build queue.
 fn scout_thread(registry: &FcFontRegistry) {
-    let font_dirs = get_font_directories();
+    let (font_dirs, aliases) = discover_font_directories_and_aliases();
+    if !aliases.is_empty() {
+        // Merge rather than replace: a fonts.conf that only customizes e.g. "sans-serif"
+        // shouldn't wipe out a "monospace" alias registered via `register_generic_alias`
+        // (or vice versa, since this thread may run before or after such a call).
+        let mut generic_aliases = registry.generic_aliases.write().unwrap();
+        for (generic, preferred) in aliases {
+            generic_aliases.insert(generic, preferred);
+        }
+    }
 
     let mut all_font_paths: Vec<(PathBuf, String)> = Vec::new();
 
@@ -899,7 +2238,6 @@ fn scout_thread(registry: &FcFontRegistry) {
     // Populate known_paths and build queue
     {
         let mut known_paths = registry.known_paths.write().unwrap();
-        let mut queue = registry.build_queue.lock().unwrap();
 
         for (path, guessed_family) in &all_font_paths {
             known_paths
@@ -916,7 +2254,7 @@ fn scout_thread(registry: &FcFontRegistry) {
                 Priority::Low
             };
 
-            queue.push(FcBuildJob {
+            registry.build_queue.push(FcBuildJob {
                 priority,
                 path: path.clone(),
                 font_index: None,
@@ -924,16 +2262,13 @@ fn scout_thread(registry: &FcFontRegistry) {
             });
         }
 
-        // Sort queue so highest priority is at the end (pop from end)
-        queue.sort();
-
         registry
             .files_discovered
             .store(all_font_paths.len(), Ordering::Relaxed);
     }
 
     registry.scan_complete.store(true, Ordering::Release);
-    registry.queue_condvar.notify_all();
+    registry.parker.wake_all();
 }
 
 /// Recursively collect font files from a directory, guessing family names from filenames.
@@ -964,7 +2299,7 @@ fn is_font_file(path: &PathBuf) -> bool {
     match path.extension().and_then(|e| e.to_str()) {
         Some(ext) => matches!(
             ext.to_lowercase().as_str(),
-            "ttf" | "otf" | "ttc" | "woff" | "woff2" | "dfont"
+            "ttf" | "otf" | "ttc" | "woff" | "woff2" | "dfont" | "pfa" | "pfb"
         ),
         None => false,
     }
@@ -1017,8 +2352,13 @@ fn guess_family_from_filename(path: &PathBuf) -> String {
         .collect()
 }
 
-/// Get OS-specific font directories.
-fn get_font_directories() -> Vec<PathBuf> {
+/// Get OS-specific font directories, and (on Linux) the generic-family aliases read from
+/// the system's fontconfig config.
+///
+/// On Linux this tries `/etc/fonts/fonts.conf` (and `/etc/fonts/local.conf`, whose entries
+/// are added after fonts.conf's) first; if neither exists or parses, falls back to the hardcoded list
+/// below, same as every other platform.
+fn discover_font_directories_and_aliases() -> (Vec<PathBuf>, BTreeMap<String, Vec<String>>) {
     let mut dirs = Vec::new();
 
     #[cfg(target_os = "macos")]
@@ -1033,8 +2373,11 @@ fn get_font_directories() -> Vec<PathBuf> {
 
     #[cfg(target_os = "linux")]
     {
-        // Parse /etc/fonts/fonts.conf for font directories
-        // For simplicity, use the common locations directly
+        if let Some((conf_dirs, aliases)) = parse_system_fontconfig() {
+            return (conf_dirs, aliases);
+        }
+
+        // No usable fontconfig config found; fall back to the common locations.
         dirs.push(PathBuf::from("/usr/share/fonts"));
         dirs.push(PathBuf::from("/usr/local/share/fonts"));
         if let Ok(home) = std::env::var("HOME") {
@@ -1057,7 +2400,236 @@ fn get_font_directories() -> Vec<PathBuf> {
         )));
     }
 
-    dirs
+    (dirs, BTreeMap::new())
+}
+
+/// Parse `/etc/fonts/fonts.conf` and then `/etc/fonts/local.conf` (whose alias families are
+/// appended after the former's; directories from both are merged) into a combined list of font directories and a map
+/// of generic family → preferred concrete families. Returns `None` if neither file is
+/// present or readable, so the caller can fall back to the hardcoded directory list.
+#[cfg(target_os = "linux")]
+fn parse_system_fontconfig() -> Option<(Vec<PathBuf>, BTreeMap<String, Vec<String>>)> {
+    let mut dirs = Vec::new();
+    let mut aliases: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut visited = HashSet::new();
+    let mut found_any = false;
+
+    for root in ["/etc/fonts/fonts.conf", "/etc/fonts/local.conf"] {
+        if parse_fontconfig_file(
+            &PathBuf::from(root),
+            &mut visited,
+            &mut dirs,
+            &mut aliases,
+        ) {
+            found_any = true;
+        }
+    }
+
+    if !found_any {
+        return None;
+    }
+
+    dirs.sort();
+    dirs.dedup();
+    Some((dirs, aliases))
+}
+
+/// Parse one fontconfig XML file, recursing into `<include>`d files/directories. Returns
+/// `true` if the file (or anything it included) was successfully read, so the caller can
+/// tell "config exists but is empty" from "no config at all".
+#[cfg(target_os = "linux")]
+fn parse_fontconfig_file(
+    path: &PathBuf,
+    visited: &mut HashSet<PathBuf>,
+    dirs: &mut Vec<PathBuf>,
+    aliases: &mut BTreeMap<String, Vec<String>>,
+) -> bool {
+    let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+    if !visited.insert(canonical) {
+        // Already visited: either a genuine `<include>` cycle, or the same conf.d entry
+        // reached twice (e.g. via both fonts.conf and local.conf).
+        return false;
+    }
+
+    let xml = match std::fs::read_to_string(path) {
+        Ok(xml) => xml,
+        Err(_) => return false,
+    };
+
+    let mut includes = Vec::new();
+    parse_fonts_conf_xml(&xml, dirs, aliases, &mut includes);
+
+    for (prefix, include_path, ignore_missing) in includes {
+        let resolved = match crate::process_path(&prefix, include_path, true) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        let meta = match std::fs::metadata(&resolved) {
+            Ok(m) => m,
+            Err(_) => {
+                let _ = ignore_missing; // unreadable either way; nothing more to do
+                continue;
+            }
+        };
+
+        if meta.is_dir() {
+            let mut entries: Vec<PathBuf> = std::fs::read_dir(&resolved)
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("conf"))
+                .collect();
+            entries.sort();
+            for entry in entries {
+                parse_fontconfig_file(&entry, visited, dirs, aliases);
+            }
+        } else {
+            parse_fontconfig_file(&resolved, visited, dirs, aliases);
+        }
+    }
+
+    true
+}
+
+/// Tokenize a single fontconfig XML document, collecting `<dir prefix=".."/>` directories,
+/// `<alias><family>generic</family><prefer><family>concrete</family>...</prefer></alias>`
+/// (`<accept>` entries are folded in the same as `<prefer>`) and `<default><family>concrete
+/// </family></default>` mappings, and the `(prefix, path, ignore_missing)` of every
+/// `<include>` for the caller to resolve and recurse into.
+#[cfg(target_os = "linux")]
+fn parse_fonts_conf_xml(
+    input: &str,
+    dirs: &mut Vec<PathBuf>,
+    aliases: &mut BTreeMap<String, Vec<String>>,
+    includes: &mut Vec<(Option<String>, PathBuf, bool)>,
+) {
+    use xmlparser::ElementEnd;
+    use xmlparser::Token::*;
+
+    // A handful of flat parsing states; fontconfig's schema nests `<family>` inside
+    // `<alias>`/`<prefer>`/`<default>`, so (unlike `<dir>`/`<include>`) we track a small
+    // element stack instead of a single current-tag flag.
+    #[derive(PartialEq)]
+    enum Tag {
+        Dir,
+        Include,
+        Alias,
+        Prefer,
+        Accept,
+        Default,
+    }
+
+    let mut stack: Vec<Tag> = Vec::new();
+    let mut current_prefix: Option<String> = None;
+    let mut ignore_missing = false;
+    let mut alias_generic: Option<String> = None;
+    let mut alias_prefers: Vec<String> = Vec::new();
+    let mut default_families: Vec<String> = Vec::new();
+
+    for token_result in xmlparser::Tokenizer::from(input) {
+        let token = match token_result {
+            Ok(t) => t,
+            Err(_) => return,
+        };
+
+        match token {
+            ElementStart { local, .. } => {
+                let tag = match local.as_str() {
+                    "dir" => Tag::Dir,
+                    "include" => Tag::Include,
+                    "alias" => Tag::Alias,
+                    "prefer" => Tag::Prefer,
+                    "accept" => Tag::Accept,
+                    "default" => Tag::Default,
+                    _ => continue,
+                };
+                if tag == Tag::Include {
+                    current_prefix = None;
+                    ignore_missing = false;
+                }
+                if tag == Tag::Alias {
+                    alias_generic = None;
+                    alias_prefers.clear();
+                }
+                stack.push(tag);
+            }
+            Attribute { local, value, .. } => match stack.last() {
+                Some(Tag::Dir) | Some(Tag::Include) if local.as_str() == "prefix" => {
+                    current_prefix = Some(value.as_str().to_string());
+                }
+                Some(Tag::Include) if local.as_str() == "ignore_missing" => {
+                    ignore_missing = value.as_str() == "yes";
+                }
+                _ => {}
+            },
+            Text { text, .. } => {
+                let text = text.as_str().trim();
+                if text.is_empty() {
+                    continue;
+                }
+                match stack.last() {
+                    Some(Tag::Dir) => {
+                        if let Some(resolved) =
+                            crate::process_path(&current_prefix, PathBuf::from(text), false)
+                        {
+                            dirs.push(resolved);
+                        }
+                    }
+                    Some(Tag::Include) => {
+                        includes.push((
+                            current_prefix.clone(),
+                            PathBuf::from(text),
+                            ignore_missing,
+                        ));
+                    }
+                    Some(Tag::Prefer) | Some(Tag::Accept) => alias_prefers.push(text.to_string()),
+                    Some(Tag::Alias) => alias_generic = Some(text.to_lowercase()),
+                    Some(Tag::Default) => default_families.push(text.to_string()),
+                    None => {}
+                }
+            }
+            ElementEnd { end, .. } => {
+                let closed = match end {
+                    ElementEnd::Close(_, name) => name.as_str(),
+                    _ => continue,
+                };
+                match closed {
+                    "alias" => {
+                        if let Some(generic) = alias_generic.take() {
+                            if !alias_prefers.is_empty() {
+                                aliases
+                                    .entry(generic)
+                                    .or_insert_with(Vec::new)
+                                    .extend(alias_prefers.drain(..));
+                            }
+                        }
+                        current_prefix = None;
+                    }
+                    "dir" | "include" | "prefer" | "accept" | "default" => {
+                        if closed != "prefer" && closed != "accept" {
+                            current_prefix = None;
+                        }
+                    }
+                    _ => continue,
+                }
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    // `<default><family>..</family></default>` names a system-wide fallback family that
+    // applies to every generic not covered by a more specific `<alias>`.
+    if !default_families.is_empty() {
+        for generic in ["serif", "sans-serif", "monospace", "cursive", "fantasy", "system-ui"] {
+            aliases
+                .entry(generic.to_string())
+                .or_insert_with(Vec::new)
+                .extend(default_families.iter().cloned());
+        }
+    }
 }
 
 /// Get common font families that should be loaded at High priority.
@@ -1116,34 +2688,36 @@ fn builder_thread(registry: &FcFontRegistry) {
             return;
         }
 
-        // Pop the highest-priority job
-        let job = {
-            let mut queue = registry.build_queue.lock().unwrap();
-
-            loop {
-                if registry.shutdown.load(Ordering::Relaxed) {
-                    return;
-                }
+        // Pop the highest-priority job (Critical -> High -> Medium -> Low), without ever
+        // taking a global lock.
+        let job = loop {
+            if registry.shutdown.load(Ordering::Relaxed) {
+                return;
+            }
 
-                if let Some(job) = queue.pop() {
-                    break job;
-                }
+            if let Some(job) = registry.build_queue.try_pop() {
+                break job;
+            }
 
-                // If scan is complete and queue is empty, we're done
-                if registry.scan_complete.load(Ordering::Acquire) && queue.is_empty() {
-                    registry.build_complete.store(true, Ordering::Release);
-                    // Signal any waiting requests that we're done
-                    registry.request_complete.notify_all();
-                    return;
+            // If scan is complete and every lane is empty, we're done
+            if registry.scan_complete.load(Ordering::Acquire) && registry.build_queue.is_empty() {
+                // Only the Builder thread that wins this race writes the disk cache, so a
+                // freshly-completed scan isn't serialized once per thread.
+                if registry
+                    .build_complete
+                    .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+                {
+                    #[cfg(feature = "cache")]
+                    registry.save_to_disk_cache();
                 }
-
-                // Wait for new jobs
-                queue = registry
-                    .queue_condvar
-                    .wait_timeout(queue, Duration::from_millis(100))
-                    .unwrap()
-                    .0;
+                // Signal any waiting requests that we're done
+                registry.request_complete.notify_all();
+                return;
             }
+
+            // Park until a push wakes us, or the timeout elapses as a safety net.
+            registry.parker.park_timeout(Duration::from_millis(100));
         };
 
         // Deduplication: skip if already processed
@@ -1157,6 +2731,11 @@ fn builder_thread(registry: &FcFontRegistry) {
 
         // Parse the font file
         if let Some(results) = crate::FcParseFont(&job.path) {
+            // Map the file once here, shared by every face it yields, instead of each
+            // face re-opening it the first time a rasterizer calls `get_font_bytes`.
+            #[cfg(feature = "mmap")]
+            registry.mmap_and_cache_path(&job.path);
+
             for (pattern, font_path) in results {
                 registry.insert_font(pattern, font_path);
             }
@@ -1209,17 +2788,80 @@ pub struct FontIndexEntry {
 
 #[cfg(feature = "cache")]
 impl FcFontRegistry {
-    /// Load font metadata from the on-disk cache.
+    /// Load font metadata from the on-disk cache (the OS-standard cache directory).
     ///
     /// If a valid cache exists, all font patterns are loaded into the registry
     /// immediately. The Scout thread will verify staleness in the background.
     pub fn load_from_disk_cache(&self) -> bool {
-        let cache_path = match get_font_cache_path() {
-            Some(p) => p,
-            None => return false,
+        match get_font_cache_path() {
+            Some(path) => self.load_manifest(&path),
+            None => false,
+        }
+    }
+
+    /// Save the current registry state to the on-disk cache (the OS-standard cache
+    /// directory).
+    pub fn save_to_disk_cache(&self) {
+        if let Some(path) = get_font_cache_path() {
+            self.save_manifest(&path);
+        }
+    }
+
+    /// Write a manifest of every loaded face — file path, face index, resolved
+    /// `FcPattern` (which carries its Unicode coverage ranges), and the file's mtime/size
+    /// at load time — to `path`.
+    pub fn save_manifest(&self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        let disk_fonts = self.disk_fonts.read().unwrap();
+        let metadata_map = self.metadata.read().unwrap();
+
+        let mut entries: BTreeMap<String, FontCacheEntry> = BTreeMap::new();
+
+        for (id, font_path) in disk_fonts.iter() {
+            if let Some(pattern) = metadata_map.get(id) {
+                let entry = entries
+                    .entry(font_path.path.clone())
+                    .or_insert_with(|| {
+                        let (mtime_secs, file_size) = get_file_metadata(&font_path.path);
+                        FontCacheEntry {
+                            mtime_secs,
+                            file_size,
+                            font_indices: Vec::new(),
+                        }
+                    });
+
+                entry.font_indices.push(FontIndexEntry {
+                    pattern: pattern.clone(),
+                    font_index: font_path.font_index,
+                });
+            }
+        }
+
+        let manifest = FontManifest {
+            version: FontManifest::CURRENT_VERSION,
+            entries,
         };
 
-        let data = match std::fs::read(&cache_path) {
+        if let Ok(data) = bincode::serialize(&manifest) {
+            let _ = std::fs::write(path, data);
+        }
+    }
+
+    /// Load a manifest written by `save_manifest`, repopulating `patterns`, `metadata`,
+    /// `disk_fonts`, the token index and the coverage index *without parsing any font
+    /// files*, then sets `cache_loaded` so `request_fonts` takes the fast path.
+    ///
+    /// Each entry is validated against its file's current mtime/size: a match is trusted
+    /// outright and the Builder skips it entirely; a mismatch (or a now-missing file) is
+    /// treated as stale — its cached patterns are *not* trusted, and the path is instead
+    /// enqueued as a Medium-priority Builder job so it gets re-parsed and reconciled.
+    /// Genuinely new fonts (paths that were never in the manifest at all) are still
+    /// found the normal way, by the Scout thread's filesystem sweep.
+    pub fn load_manifest(&self, path: &Path) -> bool {
+        let data = match std::fs::read(path) {
             Ok(d) => d,
             Err(_) => return false,
         };
@@ -1238,10 +2880,22 @@ impl FcFontRegistry {
         let mut metadata = self.metadata.write().unwrap();
         let mut token_index = self.token_index.write().unwrap();
         let mut font_tokens = self.font_tokens.write().unwrap();
+        let mut coverage_index = self.coverage_index.write().unwrap();
+        let mut processed = self.processed_paths.lock().unwrap();
+        let mut cache_loaded_ids = self.cache_loaded_ids.lock().unwrap();
 
         let mut count = 0usize;
-        let mut processed = self.processed_paths.lock().unwrap();
+        let mut stale_paths: Vec<String> = Vec::new();
+
         for (path_str, entry) in &manifest.entries {
+            let (mtime_secs, file_size) = get_file_metadata(path_str);
+            if (mtime_secs, file_size) != (entry.mtime_secs, entry.file_size) {
+                // File changed (or vanished) since the manifest was written; don't
+                // trust its cached patterns.
+                stale_paths.push(path_str.clone());
+                continue;
+            }
+
             // Mark this file as already processed so builder threads skip it
             processed.insert(PathBuf::from(path_str));
             for idx_entry in &entry.font_indices {
@@ -1252,6 +2906,7 @@ impl FcFontRegistry {
                     &idx_entry.pattern,
                     id,
                 );
+                Self::index_coverage_static(&mut coverage_index, &idx_entry.pattern, id);
                 patterns.insert(idx_entry.pattern.clone(), id);
                 disk_fonts.insert(
                     id,
@@ -1261,10 +2916,31 @@ impl FcFontRegistry {
                     },
                 );
                 metadata.insert(id, idx_entry.pattern.clone());
+                cache_loaded_ids.insert(id);
                 count += 1;
             }
         }
+
+        drop(cache_loaded_ids);
         drop(processed);
+        drop(patterns);
+        drop(disk_fonts);
+        drop(metadata);
+        drop(token_index);
+        drop(font_tokens);
+        drop(coverage_index);
+
+        for path_str in stale_paths {
+            let path = PathBuf::from(&path_str);
+            let guessed_family = guess_family_from_filename(&path);
+            self.build_queue.push(FcBuildJob {
+                priority: Priority::Medium,
+                path,
+                font_index: None,
+                guessed_family,
+            });
+        }
+        self.parker.wake_all();
 
         self.faces_loaded.store(count, Ordering::Relaxed);
         // Don't set files_parsed here — that counter tracks builder thread work.
@@ -1273,57 +2949,10 @@ impl FcFontRegistry {
 
         true
     }
-
-    /// Save the current registry state to the on-disk cache.
-    pub fn save_to_disk_cache(&self) {
-        let cache_path = match get_font_cache_path() {
-            Some(p) => p,
-            None => return,
-        };
-
-        // Create parent directories
-        if let Some(parent) = cache_path.parent() {
-            let _ = std::fs::create_dir_all(parent);
-        }
-
-        let disk_fonts = self.disk_fonts.read().unwrap();
-        let metadata_map = self.metadata.read().unwrap();
-
-        let mut entries: BTreeMap<String, FontCacheEntry> = BTreeMap::new();
-
-        for (id, font_path) in disk_fonts.iter() {
-            if let Some(pattern) = metadata_map.get(id) {
-                let entry = entries
-                    .entry(font_path.path.clone())
-                    .or_insert_with(|| {
-                        let (mtime_secs, file_size) = get_file_metadata(&font_path.path);
-                        FontCacheEntry {
-                            mtime_secs,
-                            file_size,
-                            font_indices: Vec::new(),
-                        }
-                    });
-
-                entry.font_indices.push(FontIndexEntry {
-                    pattern: pattern.clone(),
-                    font_index: font_path.font_index,
-                });
-            }
-        }
-
-        let manifest = FontManifest {
-            version: FontManifest::CURRENT_VERSION,
-            entries,
-        };
-
-        if let Ok(data) = bincode::serialize(&manifest) {
-            let _ = std::fs::write(&cache_path, data);
-        }
-    }
 }
 
 /// Get file mtime and size.
-#[cfg(feature = "cache")]
+#[cfg(any(feature = "cache", feature = "watch"))]
 fn get_file_metadata(path: &str) -> (u64, u64) {
     match std::fs::metadata(path) {
         Ok(meta) => {
@@ -1380,8 +3009,190 @@ fn get_cache_base_dir() -> Option<PathBuf> {
     }
 }
 
+// ── Live Watch ──────────────────────────────────────────────────────────────
+
+/// Watch every directory from `discover_font_directories_and_aliases()` for changes,
+/// keeping `registry` in sync for as long as the returned `notify::RecommendedWatcher` is
+/// alive (dropping it stops the watch). Lets a long-lived host — an editor, a terminal —
+/// pick up fonts installed, edited, or removed at runtime without a full restart.
+///
+/// On create/modify, the file's current mtime+size is compared against what was recorded
+/// when it was last parsed; a genuine change (or a brand-new file) is re-enqueued as a
+/// `Priority::High` Builder job. On delete, every `FontId` backed by that path is purged
+/// from `patterns`/`metadata`/`token_index`/`font_tokens`/`disk_fonts`/`coverage_index`,
+/// pending requests are re-checked, and (with `feature = "cache"`) the on-disk manifest is
+/// rewritten to match.
+#[cfg(feature = "watch")]
+pub fn watch_font_directories(
+    registry: &Arc<FcFontRegistry>,
+) -> Option<notify::RecommendedWatcher> {
+    use notify::{RecursiveMode, Watcher};
+
+    let (dirs, _aliases) = discover_font_directories_and_aliases();
+    if dirs.is_empty() {
+        return None;
+    }
+
+    let watched = Arc::clone(registry);
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            handle_watch_event(&watched, &event);
+        }
+    })
+    .ok()?;
+
+    for dir in &dirs {
+        let _ = watcher.watch(dir, RecursiveMode::Recursive);
+    }
+
+    Some(watcher)
+}
+
+/// Route one filesystem event to a reconcile (create/modify) or a purge (remove) for
+/// every font file path it touches.
+#[cfg(feature = "watch")]
+fn handle_watch_event(registry: &FcFontRegistry, event: &notify::Event) {
+    use notify::EventKind;
+
+    for path in &event.paths {
+        if !is_font_file(path) {
+            continue;
+        }
+        let path_str = match path.to_str() {
+            Some(s) => s.to_string(),
+            None => continue,
+        };
+
+        match event.kind {
+            EventKind::Remove(_) => remove_font_file(registry, &path_str),
+            EventKind::Create(_) | EventKind::Modify(_) => {
+                reconcile_font_file(registry, path.clone(), path_str)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A font file was created or modified: re-enqueue it for parsing if its mtime/size
+/// actually changed since it was last parsed (or it's new), so an unrelated event (e.g.
+/// an `atime` touch) doesn't trigger a needless reparse.
+#[cfg(feature = "watch")]
+fn reconcile_font_file(registry: &FcFontRegistry, path: PathBuf, path_str: String) {
+    let current = get_file_metadata(&path_str);
+    if current == (0, 0) {
+        // Vanished between the event firing and us stat-ing it; treat it as a delete.
+        remove_font_file(registry, &path_str);
+        return;
+    }
+
+    let unchanged = registry.file_metadata.read().unwrap().get(&path_str).copied() == Some(current);
+    if unchanged {
+        return;
+    }
+
+    registry
+        .file_metadata
+        .write()
+        .unwrap()
+        .insert(path_str, current);
+    registry.processed_paths.lock().unwrap().remove(&path);
+
+    let guessed_family = guess_family_from_filename(&path);
+    registry.build_queue.push(FcBuildJob {
+        priority: Priority::High,
+        path,
+        font_index: None,
+        guessed_family,
+    });
+    registry.parker.wake_all();
+}
+
+/// A font file was deleted: purge every `FontId` it backed from every index.
+#[cfg(feature = "watch")]
+fn remove_font_file(registry: &FcFontRegistry, path_str: &str) {
+    let removed_ids: Vec<FontId> = {
+        let disk_fonts = registry.disk_fonts.read().unwrap();
+        disk_fonts
+            .iter()
+            .filter(|(_, font_path)| font_path.path == path_str)
+            .map(|(id, _)| *id)
+            .collect()
+    };
+    if removed_ids.is_empty() {
+        return;
+    }
+
+    {
+        let mut patterns = registry.patterns.write().unwrap();
+        let mut metadata = registry.metadata.write().unwrap();
+        let mut token_index = registry.token_index.write().unwrap();
+        let mut font_tokens = registry.font_tokens.write().unwrap();
+        let mut disk_fonts = registry.disk_fonts.write().unwrap();
+        let mut coverage_index = registry.coverage_index.write().unwrap();
+
+        for id in &removed_ids {
+            disk_fonts.remove(id);
+
+            if let Some(pattern) = metadata.remove(id) {
+                patterns.remove(&pattern);
+                let ranges = if !pattern.cmap_coverage.is_empty() {
+                    &pattern.cmap_coverage
+                } else {
+                    &pattern.unicode_ranges
+                };
+                for range in ranges {
+                    if let Some(ids) = coverage_index.get_mut(&range.start) {
+                        ids.remove(id);
+                    }
+                }
+            }
+
+            if let Some(tokens) = font_tokens.remove(id) {
+                for token in tokens {
+                    if let Some(ids) = token_index.get_mut(&token) {
+                        ids.remove(id);
+                    }
+                }
+            }
+        }
+    }
+
+    registry.file_metadata.write().unwrap().remove(path_str);
+    registry
+        .processed_paths
+        .lock()
+        .unwrap()
+        .remove(&PathBuf::from(path_str));
+
+    if let Ok(mut cache) = registry.chain_cache.lock() {
+        cache.clear();
+    }
+
+    registry.check_and_signal_pending_requests();
+
+    #[cfg(feature = "cache")]
+    registry.save_to_disk_cache();
+}
+
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
+/// Returns true if `codepoint` is a combining mark or variation selector, i.e. it must
+/// never start its own run in `FcFontRegistry::match_cluster` — it has to stay attached
+/// to whatever font was chosen for its base character.
+fn is_combining_or_variation_selector(codepoint: u32) -> bool {
+    matches!(
+        codepoint,
+        0x0300..=0x036F   // Combining Diacritical Marks
+            | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+            | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+            | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+            | 0xFE00..=0xFE0F // Variation Selectors
+            | 0xFE20..=0xFE2F // Combining Half Marks
+            | 0x200D          // Zero Width Joiner (emoji ZWJ sequences)
+            | 0xE0100..=0xE01EF // Variation Selectors Supplement
+    )
+}
+
 /// Normalize a family name for comparison: lowercase, strip spaces/hyphens/underscores.
 fn normalize_family_name(name: &str) -> String {
     name.chars()
@@ -183,22 +183,23 @@ fn test_weight_matching() {
         .count();
     assert!(family_mismatch_traces > 0, "Expected family mismatch trace messages");
     
-    // Query that doesn't match - weight mismatch
+    // Query for a weight the family doesn't have - should substitute the nearest
+    // available weight (Normal, 100 away) instead of rejecting the family outright.
     trace.clear();
     let light_query = FcPattern {
         family: Some("Test Family".to_string()),
         weight: FcWeight::Light,
         ..Default::default()
     };
-    
+
     let matches = cache.query(&light_query, &mut trace);
-    assert!(matches.is_none(), "Should not match with weight mismatch");
-    
-    // Check trace messages for weight mismatch
-    let weight_mismatch_traces = trace.iter()
-        .filter(|msg| matches!(msg.reason, MatchReason::WeightMismatch { .. }))
+    assert!(matches.is_some(), "Should substitute the nearest weight instead of rejecting");
+
+    // Check trace messages for the weight substitution
+    let weight_substituted_traces = trace.iter()
+        .filter(|msg| matches!(msg.reason, MatchReason::WeightSubstituted { .. }))
         .count();
-    assert!(weight_mismatch_traces > 0, "Expected weight mismatch trace messages");
+    assert!(weight_substituted_traces > 0, "Expected weight substitution trace messages");
     
     // Test weight matching algorithm
     let available_weights = [FcWeight::Light, FcWeight::Normal, FcWeight::Bold];
@@ -323,4 +324,36 @@ fn test_trace_messages() {
         matches!(msg.reason, MatchReason::UnicodeRangeMismatch { .. })
     });
     assert!(range_mismatch, "Unicode range mismatch trace message not found");
+}
+
+#[test]
+fn test_contains_char_cmap_vs_unicode_ranges() {
+    // When `cmap_coverage` (the exact per-glyph coverage built from the `cmap` table) is
+    // present, it's authoritative and `unicode_ranges` (the coarse OS/2 block hints) are
+    // ignored entirely - even where the two disagree.
+    let cmap_pattern = FcPattern {
+        cmap_coverage: vec![
+            UnicodeRange { start: 0x0041, end: 0x0041 }, // just 'A'
+            UnicodeRange { start: 0x4E00, end: 0x4E03 }, // a handful of CJK ideographs
+        ],
+        unicode_ranges: vec![UnicodeRange { start: 0x0000, end: 0xFFFF }], // would match everything
+        ..Default::default()
+    };
+    assert!(cmap_pattern.contains_char('A'));
+    assert!(!cmap_pattern.contains_char('B'), "cmap_coverage is authoritative over the wider unicode_ranges hint");
+    assert!(cmap_pattern.contains_char('\u{4E00}'));
+    assert!(cmap_pattern.contains_char('\u{4E03}'), "range end is inclusive");
+    assert!(!cmap_pattern.contains_char('\u{4E04}'), "one past the coalesced range");
+
+    // Without cmap_coverage, falls back to the coarser unicode_ranges.
+    let fallback_pattern = FcPattern {
+        unicode_ranges: vec![UnicodeRange { start: 0x0041, end: 0x005A }], // A-Z
+        ..Default::default()
+    };
+    assert!(fallback_pattern.contains_char('M'));
+    assert!(!fallback_pattern.contains_char('m'));
+
+    // With neither populated (a bare query pattern), every character matches.
+    let bare_pattern = FcPattern::default();
+    assert!(bare_pattern.contains_char('x'));
 }
\ No newline at end of file
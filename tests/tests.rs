@@ -309,7 +309,8 @@ fn test_weight_matching() {
         "Expected family mismatch trace messages"
     );
 
-    // Query that doesn't match - weight mismatch
+    // Query for a weight the family doesn't have - should substitute the nearest
+    // available weight (Normal, 100 away) instead of rejecting the family outright.
     trace.clear();
     let light_query = FcPattern {
         family: Some("Test Family".to_string()),
@@ -318,16 +319,19 @@ fn test_weight_matching() {
     };
 
     let matches = cache.query(&light_query, &mut trace);
-    assert!(matches.is_none(), "Should not match with weight mismatch");
+    assert!(
+        matches.is_some(),
+        "Should substitute the nearest weight instead of rejecting"
+    );
 
-    // Check trace messages for weight mismatch
-    let weight_mismatch_traces = trace
+    // Check trace messages for the weight substitution
+    let weight_substituted_traces = trace
         .iter()
-        .filter(|msg| matches!(msg.reason, MatchReason::WeightMismatch { .. }))
+        .filter(|msg| matches!(msg.reason, MatchReason::WeightSubstituted { .. }))
         .count();
     assert!(
-        weight_mismatch_traces > 0,
-        "Expected weight mismatch trace messages"
+        weight_substituted_traces > 0,
+        "Expected weight substitution trace messages"
     );
 
     // Test weight matching algorithm
@@ -707,6 +711,115 @@ fn test_failing_isolated() {
     assert_eq!(result.unwrap().id, arial_id, "Should match Arial font ID");
 }
 
+#[test]
+fn test_query_face_set() {
+    // Create fixed font IDs for deterministic testing
+    let arial_id = FontId(1);
+    let arial_bold_id = FontId(2);
+    let courier_id = FontId(3);
+    let fira_id = FontId(4);
+    let noto_cjk_id = FontId(5);
+
+    let fonts = getfonts(arial_id, arial_bold_id, courier_id, fira_id, noto_cjk_id);
+
+    let mut cache = FcFontCache::default();
+    for (id, pattern, font) in fonts {
+        cache.with_memory_font_with_id(id, pattern, font);
+    }
+
+    let mut trace = Vec::new();
+    let arial_query = FcPattern {
+        family: Some("Arial".to_string()),
+        ..Default::default()
+    };
+
+    let face_set = cache.query_face_set(&arial_query, &mut trace);
+
+    // Regular and bold both exist in the "Arial" family.
+    let regular = face_set.regular.expect("should find Arial regular");
+    assert_eq!(regular.font_match.id, arial_id);
+    assert!(!regular.is_synthetic);
+
+    let bold = face_set.bold.expect("should find Arial bold");
+    assert_eq!(bold.font_match.id, arial_bold_id);
+    assert!(!bold.is_synthetic);
+
+    // Neither an italic nor a bold-italic Arial face exists, so both slots should still
+    // be filled via the fallback machinery, but flagged as synthetic (faux-slanted).
+    let italic = face_set.italic.expect("should still fill the italic slot");
+    assert!(italic.is_synthetic);
+
+    let bold_italic = face_set
+        .bold_italic
+        .expect("should still fill the bold-italic slot");
+    assert!(bold_italic.is_synthetic);
+}
+
+#[test]
+fn test_language_filtering() {
+    let latin_id = FontId(101);
+    let cjk_id = FontId(102);
+
+    let latin_font = FcFont {
+        bytes: vec![0, 1, 2, 3],
+        font_index: 0,
+        id: "latin-only".to_string(),
+    };
+    let latin_pattern = FcPattern {
+        name: Some("Latin Only".to_string()),
+        family: Some("Latin Only".to_string()),
+        languages: vec!["en".to_string()],
+        ..Default::default()
+    };
+
+    let cjk_font = FcFont {
+        bytes: vec![4, 5, 6, 7],
+        font_index: 0,
+        id: "noto-sans-cjk".to_string(),
+    };
+    let cjk_pattern = FcPattern {
+        name: Some("Noto Sans CJK".to_string()),
+        family: Some("Noto Sans CJK".to_string()),
+        languages: vec!["ja".to_string(), "zh-Hans".to_string()],
+        ..Default::default()
+    };
+
+    let mut cache = FcFontCache::default();
+    cache.with_memory_font_with_id(latin_id, latin_pattern, latin_font);
+    cache.with_memory_font_with_id(cjk_id, cjk_pattern, cjk_font);
+
+    // Only the CJK font lists Japanese in its declared `languages`, so a query
+    // requesting "ja" should pick it out from every other font with declared language coverage,
+    // without needing to name the family or probe the cmap for individual CJK codepoints.
+    let mut trace = Vec::new();
+    let ja_query = FcPattern {
+        languages: vec!["ja".to_string()],
+        ..Default::default()
+    };
+
+    let matches = cache.query_all(&ja_query, &mut trace);
+    assert_eq!(matches.len(), 1, "Only the CJK font covers \"ja\"");
+    assert_eq!(matches[0].id, cjk_id);
+
+    let language_mismatch_traces = trace
+        .iter()
+        .filter(|msg| matches!(msg.reason, MatchReason::LanguageMismatch { .. }))
+        .count();
+    assert!(
+        language_mismatch_traces > 0,
+        "Expected a language mismatch trace for the Latin-only font"
+    );
+
+    // A language neither font declares support for should reject both.
+    trace.clear();
+    let unsupported_query = FcPattern {
+        languages: vec!["th".to_string()],
+        ..Default::default()
+    };
+    let matches = cache.query_all(&unsupported_query, &mut trace);
+    assert!(matches.is_empty(), "No font declares Thai coverage");
+}
+
 #[test]
 fn test_failing_isolated_2() {
     // Create fixed font IDs for deterministic testing
@@ -741,3 +854,137 @@ fn test_failing_isolated_2() {
         "Should match Arial Bold font ID"
     );
 }
+
+#[cfg(all(feature = "std", feature = "parsing"))]
+#[test]
+fn test_build_with_config_alias_substitution() {
+    let arial_id = FontId(100);
+    let arial_pattern = FcPattern {
+        name: Some("Arial".to_string()),
+        family: Some("Arial".to_string()),
+        ..Default::default()
+    };
+    let arial_font = FcFont {
+        bytes: vec![1, 2, 3, 4],
+        font_index: 0,
+        id: "arial-regular".to_string(),
+    };
+
+    let conf_path = std::env::temp_dir().join("rust_fontconfig_test_alias_fonts.conf");
+    std::fs::write(
+        &conf_path,
+        r#"<?xml version="1.0"?>
+<fontconfig>
+  <alias>
+    <family>Helvetica</family>
+    <accept>
+      <family>Arial</family>
+    </accept>
+  </alias>
+</fontconfig>
+"#,
+    )
+    .expect("should write temp fonts.conf");
+
+    let mut cache = FcFontCache::build_with_config(&[conf_path.clone()]);
+    cache.with_memory_font_with_id(arial_id, arial_pattern, arial_font);
+
+    let mut trace = Vec::new();
+    let helvetica_query = FcPattern {
+        family: Some("Helvetica".to_string()),
+        ..Default::default()
+    };
+
+    let result = cache.query(&helvetica_query, &mut trace);
+    assert!(
+        result.is_some(),
+        "Should resolve 'Helvetica' to 'Arial' via the <alias> substitution"
+    );
+    assert_eq!(result.unwrap().id, arial_id);
+
+    std::fs::remove_file(&conf_path).ok();
+}
+
+#[test]
+fn test_language_tier_outranks_partial_match() {
+    // Two CJK-capable fonts both satisfy a "zh-Hant" request (both declare Han coverage),
+    // but only one carries the exact tag - the other only shares the "zh" primary subtag
+    // via simplified Chinese. `calculate_language_score` should rank the exact match first
+    // regardless of scan order, the way `query_all`/`query_sorted` already do for style.
+    let exact_id = FontId(201);
+    let partial_id = FontId(202);
+
+    let exact_font = FcFont {
+        bytes: vec![1, 2, 3, 4],
+        font_index: 0,
+        id: "traditional-chinese".to_string(),
+    };
+    let exact_pattern = FcPattern {
+        name: Some("Noto Sans TC".to_string()),
+        family: Some("Noto Sans TC".to_string()),
+        languages: vec!["zh-Hant".to_string()],
+        ..Default::default()
+    };
+
+    let partial_font = FcFont {
+        bytes: vec![5, 6, 7, 8],
+        font_index: 0,
+        id: "simplified-chinese".to_string(),
+    };
+    let partial_pattern = FcPattern {
+        name: Some("Noto Sans SC".to_string()),
+        family: Some("Noto Sans SC".to_string()),
+        languages: vec!["zh-Hans".to_string()],
+        ..Default::default()
+    };
+
+    let mut cache = FcFontCache::default();
+    // Inserted in the "wrong" order on purpose, so a passing test can't be an accident of
+    // map iteration order.
+    cache.with_memory_font_with_id(partial_id, partial_pattern, partial_font);
+    cache.with_memory_font_with_id(exact_id, exact_pattern, exact_font);
+
+    let mut trace = Vec::new();
+    let query = FcPattern {
+        languages: vec!["zh-Hant".to_string()],
+        ..Default::default()
+    };
+
+    let matches = cache.query_all(&query, &mut trace);
+    assert_eq!(matches.len(), 2, "Both fonts share the \"zh\" primary subtag");
+    assert_eq!(
+        matches[0].id, exact_id,
+        "The exact \"zh-Hant\" tag match should outrank the \"zh-Hans\" partial match"
+    );
+}
+
+#[test]
+fn test_explain_query_reports_memory_source() {
+    let arial_id = FontId(301);
+    let font = FcFont {
+        bytes: vec![1, 2, 3, 4],
+        font_index: 0,
+        id: "arial".to_string(),
+    };
+    let pattern = FcPattern {
+        name: Some("Arial".to_string()),
+        family: Some("Arial".to_string()),
+        ..Default::default()
+    };
+
+    let mut cache = FcFontCache::default();
+    cache.with_memory_font_with_id(arial_id, pattern, font);
+
+    let mut trace = Vec::new();
+    let query = FcPattern {
+        family: Some("Arial".to_string()),
+        ..Default::default()
+    };
+
+    let explanations = cache.explain_query(&query, &mut trace);
+    assert_eq!(explanations.len(), 1, "Only the one memory-registered font matches");
+
+    let explanation = &explanations[0];
+    assert_eq!(explanation.id, arial_id);
+    assert_eq!(explanation.source, MatchSource::Memory);
+}